@@ -0,0 +1,132 @@
+//! Dealing helpers for a game of Blackjack.
+
+use crate::card::Card;
+use crate::deck::{Deck, Finished};
+
+/// Number of [Card]s each player and the dealer start with.
+const INITIAL_HAND_SIZE: usize = 2;
+
+/// Highest hand value that does not bust.
+const BLACKJACK: i32 = 21;
+
+/// Hand value at which the dealer stops drawing.
+const DEALER_STANDS: i32 = 17;
+
+/// Gets the Blackjack value of a hand, counting face cards as 10.
+///
+/// One Ace counts as 11 when that does not bust the hand, and every other Ace as 1.
+pub fn blackjack_value(cards: &[Card]) -> i32 {
+    let hard: i32 = cards
+        .iter()
+        .map(|card| card.rank().get_numerical_rank(false).min(10))
+        .sum();
+    let has_ace = cards.iter().any(|card| card.rank().is_ace());
+
+    match has_ace && hard + 10 <= BLACKJACK {
+        true => hard + 10,
+        false => hard,
+    }
+}
+
+impl Deck<Finished> {
+    /// Deals the opening two [Card]s to each player and the dealer.
+    ///
+    /// [Card]s go one at a time to each player in turn and then the dealer,
+    /// twice around the table. Returns the player hands and the dealer's hand,
+    /// whose first [Card] is the one shown face up. Hands come up short if the
+    /// [Deck] runs out.
+    pub fn blackjack_initial_deal(&mut self, players: usize) -> (Vec<Vec<Card>>, Vec<Card>) {
+        let mut hands = vec![Vec::with_capacity(INITIAL_HAND_SIZE); players];
+        let mut dealer = Vec::with_capacity(INITIAL_HAND_SIZE);
+
+        for _ in 0..INITIAL_HAND_SIZE {
+            for hand in hands.iter_mut() {
+                hand.extend(self.deal_top_card());
+            }
+            dealer.extend(self.deal_top_card());
+        }
+
+        (hands, dealer)
+    }
+
+    /// Deals a two [Card] hand from the top, returning it with whether it is a natural.
+    ///
+    /// A natural is an Ace with a ten-value [Card], worth 21 in two [Card]s. A hand
+    /// cut short by the [Deck] running out is never a natural.
+    pub fn deal_blackjack_hand(&mut self) -> (Vec<Card>, bool) {
+        let hand: Vec<Card> = (0..INITIAL_HAND_SIZE)
+            .filter_map(|_| self.deal_top_card())
+            .collect();
+        let natural = hand.len() == INITIAL_HAND_SIZE && blackjack_value(&hand) == BLACKJACK;
+
+        (hand, natural)
+    }
+
+    /// Plays out the dealer's hand, drawing from the top until it reaches 17 or more.
+    ///
+    /// The dealer stands on every 17, soft or hard, and stops early if the [Deck]
+    /// runs out. A final [blackjack_value] above 21 means the dealer has bust.
+    pub fn dealer_play(&mut self, dealer_hand: &mut Vec<Card>) {
+        while blackjack_value(dealer_hand) < DEALER_STANDS {
+            match self.deal_top_card() {
+                Some(card) => dealer_hand.push(card),
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(code: &str) -> Card {
+        code.parse().unwrap()
+    }
+
+    #[test]
+    fn initial_deal_alternates_players_and_dealer() {
+        let mut deck = Deck::from_compact_string("AS 2S 3S 4S 5S 6S 7S 8S 9S").unwrap();
+
+        let (hands, dealer) = deck.blackjack_initial_deal(3);
+
+        assert_eq!(deck.total_cards(), 1);
+        assert_eq!(hands[0], vec![card("AS"), card("5S")]);
+        assert_eq!(dealer, vec![card("4S"), card("8S")]);
+    }
+
+    #[test]
+    fn blackjack_value_counts_aces_soft_or_hard() {
+        assert_eq!(blackjack_value(&[card("AS"), card("KH")]), 21);
+        assert_eq!(blackjack_value(&[card("AS"), card("AH"), card("9C")]), 21);
+        assert_eq!(blackjack_value(&[card("AS"), card("7H"), card("9C")]), 17);
+        assert_eq!(blackjack_value(&[]), 0);
+    }
+
+    #[test]
+    fn dealer_draws_to_seventeen() {
+        let mut deck = Deck::from_compact_string("3C 4D 9H KS").unwrap();
+        let mut dealer = vec![card("7S"), card("5H")];
+
+        deck.dealer_play(&mut dealer);
+
+        assert_eq!(blackjack_value(&dealer), 19);
+        assert_eq!(dealer.len(), 4);
+        assert_eq!(deck.total_cards(), 2);
+    }
+
+    #[test]
+    fn natural_needs_an_ace_and_a_ten_value_card() {
+        let mut deck = Deck::from_compact_string("AS KH AD 9C").unwrap();
+
+        assert_eq!(
+            deck.deal_blackjack_hand(),
+            (vec![card("AS"), card("KH")], true)
+        );
+        assert_eq!(
+            deck.deal_blackjack_hand(),
+            (vec![card("AD"), card("9C")], false)
+        );
+        assert_eq!(deck.deal_blackjack_hand(), (Vec::new(), false));
+    }
+}
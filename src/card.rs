@@ -4,11 +4,32 @@
 //! [Suit]. The [Suit] determines which grouping of Cards the Card belongs to.
 
 use crate::rank::Rank;
-use crate::suit::Suit;
+use crate::suit::{Color, Suit};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
+
+/// Behaviour shared by every kind of card a [Deck](crate::deck::Deck) can hold.
+///
+/// Implement this for your own card type, such as Uno cards, to build and deal
+/// a [Deck](crate::deck::Deck) of it with `Deck::custom_new().custom_cards(..)`.
+pub trait PlayingCard: Copy + PartialEq + fmt::Debug {}
+
+/// A complete set of [PlayingCard]s making up a kind of deck, such as Hanafuda.
+///
+/// Build a [Deck](crate::deck::Deck) of the set with `Deck::from_card_set::<S>()`.
+pub trait CardSet {
+    /// The kind of card in the set.
+    type Card: PlayingCard;
+
+    /// Get every card in the set, in the order a fresh [Deck](crate::deck::Deck) holds them.
+    fn cards() -> Vec<Self::Card>;
+}
 
 /// A Card, representing a traditional Card from a French deck of playing cards.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Card {
     rank: Rank,
     suit: Suit,
@@ -16,10 +37,19 @@ pub struct Card {
 
 impl Card {
     /// Creates a new Card with the given [Rank] and [Suit].
-    pub fn new(rank: Rank, suit: Suit) -> Card {
+    pub const fn new(rank: Rank, suit: Suit) -> Card {
         Card { rank, suit }
     }
 
+    /// Creates a Card from positions in [Rank::VALUES] and [Suit::VALUES].
+    ///
+    /// Returns `None` if either index is out of range.
+    pub fn from_indices(rank_index: usize, suit_index: usize) -> Option<Card> {
+        let rank = Rank::from_index(rank_index)?;
+        let suit = *Suit::VALUES.get(suit_index)?;
+        Some(Card::new(rank, suit))
+    }
+
     /// Get the [Rank] of the [Card].
     pub fn rank(&self) -> Rank {
         self.rank
@@ -29,18 +59,259 @@ impl Card {
     pub fn suit(&self) -> Suit {
         self.suit
     }
+
+    /// Get a copy of the Card with its [Suit] replaced by `suit`.
+    pub fn with_suit(&self, suit: Suit) -> Card {
+        Card::new(self.rank, suit)
+    }
+
+    /// Get the point value of the Card under a game's own mapping from [Rank] to points.
+    pub fn value_with(&self, valuation: impl Fn(Rank) -> i32) -> i32 {
+        valuation(self.rank)
+    }
+
+    /// Returns whether the Card shares its [Suit] with `other`.
+    pub fn is_same_suit(&self, other: &Card) -> bool {
+        self.suit == other.suit
+    }
+
+    /// Returns whether the Card shares its [Rank] with `other`.
+    pub fn is_same_rank(&self, other: &Card) -> bool {
+        self.rank == other.rank
+    }
+
+    /// Get the two character code of the Card, such as `AS` for the Ace of Spades.
+    pub fn short_code(&self) -> String {
+        format!("{}{}", self.rank.short_code(), self.suit.short_code())
+    }
+
+    /// Get the Unicode playing card glyph of the Card, such as `🂡` for the Ace of Spades.
+    pub fn unicode_glyph(&self) -> char {
+        let suit_base = match self.suit {
+            Suit::Spades => 0x1F0A0,
+            Suit::Hearts => 0x1F0B0,
+            Suit::Diamonds => 0x1F0C0,
+            Suit::Clubs => 0x1F0D0,
+        };
+        // The block places a Knight between the Jack and Queen, which French decks skip.
+        let rank_offset = match self.rank {
+            Rank::Ace => 0x1,
+            Rank::Jack => 0xB,
+            Rank::Queen => 0xD,
+            Rank::King => 0xE,
+            rank => rank.get_numerical_rank(true) as u32,
+        };
+
+        char::from_u32(suit_base + rank_offset).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Renders the Card as a boxed, multi-line drawing for terminal display.
+    ///
+    /// The [Rank] code sits in the top left and bottom right corners with the
+    /// [Suit] symbol in the middle.
+    pub fn ascii_art(&self) -> String {
+        let rank = self.rank.short_code();
+        let suit = self.suit.symbol();
+
+        [
+            "┌─────────┐".to_string(),
+            format!("│{}        │", rank),
+            "│         │".to_string(),
+            format!("│    {}    │", suit),
+            "│         │".to_string(),
+            format!("│        {}│", rank),
+            "└─────────┘".to_string(),
+        ]
+        .join("\n")
+    }
 }
 
+impl PlayingCard for Card {}
+
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} of {}", self.rank, self.suit)
     }
 }
 
+/// Parses a [Card] from its two character short code, such as `AS` or `th`.
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        let (rank, suit) = match (chars.next(), chars.next(), chars.next()) {
+            (Some(rank), Some(suit), None) => (rank, suit),
+            _ => return Err(ParseCardError::InvalidLength(s.to_string())),
+        };
+
+        let rank = Rank::from_short_code(rank).ok_or(ParseCardError::InvalidRank(rank))?;
+        let suit = Suit::from_short_code(suit).ok_or(ParseCardError::InvalidSuit(suit))?;
+
+        Ok(Card::new(rank, suit))
+    }
+}
+
+/// Errors from parsing a [Card] from its short code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    /// The code was not exactly two characters long.
+    InvalidLength(String),
+    /// The first character is not a [Rank] code.
+    InvalidRank(char),
+    /// The second character is not a [Suit] code.
+    InvalidSuit(char),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCardError::InvalidLength(code) => {
+                write!(f, "card code '{}' must be two characters", code)
+            }
+            ParseCardError::InvalidRank(rank) => write!(f, "'{}' is not a valid rank", rank),
+            ParseCardError::InvalidSuit(suit) => write!(f, "'{}' is not a valid suit", suit),
+        }
+    }
+}
+
+impl Error for ParseCardError {}
+
+/// Compares the [Card] against a `(Rank, Suit)` pair, for terse assertions.
+impl PartialEq<(Rank, Suit)> for Card {
+    fn eq(&self, other: &(Rank, Suit)) -> bool {
+        self.rank == other.0 && self.suit == other.1
+    }
+}
+
+/// A Joker, which has a [Color] but neither a [Rank] nor a [Suit].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Joker {
+    color: Color,
+}
+
+impl Joker {
+    /// Creates a new Joker of the given [Color].
+    pub fn new(color: Color) -> Joker {
+        Joker { color }
+    }
+
+    /// Get the [Color] of the Joker.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+}
+
+impl fmt::Display for Joker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Joker", self.color)
+    }
+}
+
+/// Any kind of card found in a deck, including those without a [Rank] or [Suit].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CardKind {
+    /// A standard [Card] with a [Rank] and [Suit].
+    Standard(Card),
+    /// A [Joker], which has neither a [Rank] nor a [Suit].
+    Joker(Joker),
+}
+
+impl CardKind {
+    /// Get the numerical representation of the card's [Rank].
+    ///
+    /// Returns `None` for cards without a [Rank], such as Jokers, so they are
+    /// never mistaken for a ranked card during hand evaluation.
+    pub fn numeric_rank_opt(&self, aces_high: bool) -> Option<i32> {
+        match self {
+            CardKind::Standard(card) => Some(card.rank().get_numerical_rank(aces_high)),
+            CardKind::Joker(_) => None,
+        }
+    }
+}
+
+impl PlayingCard for CardKind {}
+
+impl From<Card> for CardKind {
+    fn from(card: Card) -> CardKind {
+        CardKind::Standard(card)
+    }
+}
+
+impl From<Joker> for CardKind {
+    fn from(joker: Joker) -> CardKind {
+        CardKind::Joker(joker)
+    }
+}
+
+impl fmt::Display for CardKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardKind::Standard(card) => write!(f, "{}", card),
+            CardKind::Joker(joker) => write!(f, "{}", joker),
+        }
+    }
+}
+
+/// A borrowed [Card] that sorts by [Rank] with Aces either high or low.
+///
+/// Cards of equal [Rank] fall back to the order of [Suit::VALUES], so sorting is
+/// total and repeatable. Comparisons use the `aces_high` flag of the left hand side.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedCard<'a> {
+    card: &'a Card,
+    aces_high: bool,
+}
+
+impl<'a> OrderedCard<'a> {
+    /// Wraps `card`, ordering Aces by `aces_high`.
+    pub fn new(card: &'a Card, aces_high: bool) -> OrderedCard<'a> {
+        OrderedCard { card, aces_high }
+    }
+
+    /// Get the wrapped [Card].
+    pub fn card(&self) -> &'a Card {
+        self.card
+    }
+
+    fn sort_key(&self, aces_high: bool) -> (i32, Option<usize>) {
+        let suit = Suit::VALUES.iter().position(|suit| *suit == self.card.suit);
+        (self.card.rank.get_numerical_rank(aces_high), suit)
+    }
+}
+
+impl PartialEq for OrderedCard<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedCard<'_> {}
+
+impl PartialOrd for OrderedCard<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedCard<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key(self.aces_high)
+            .cmp(&other.sort_key(self.aces_high))
+    }
+}
+
+/// Returns whether any [Card] appears more than once among `cards`.
+pub fn has_duplicates(cards: &[Card]) -> bool {
+    let mut seen = HashSet::with_capacity(cards.len());
+    !cards.iter().all(|card| seen.insert(card))
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
 
-    #[allow(dead_code)]
     fn setup() -> Card {
         Card::new(Rank::Ace, Suit::Hearts)
     }
@@ -56,4 +327,130 @@ mod tests {
         let card = setup();
         assert_eq!(card.rank(), Rank::Ace);
     }
+
+    #[test]
+    fn same_suit_different_rank() {
+        let ace = setup();
+        let seven = Card::new(Rank::Seven, Suit::Hearts);
+
+        assert!(ace.is_same_suit(&seven));
+        assert!(!ace.is_same_rank(&seven));
+    }
+
+    #[test]
+    fn short_code_round_trips_through_from_str() {
+        let card = Card::new(Rank::Ten, Suit::Clubs);
+
+        assert_eq!(card.short_code(), "TC");
+        assert_eq!("TC".parse::<Card>(), Ok(card));
+        assert_eq!("1C".parse::<Card>(), Err(ParseCardError::InvalidRank('1')));
+    }
+
+    #[test]
+    fn ascii_art_shows_rank_and_suit() {
+        let art = Card::new(Rank::Queen, Suit::Spades).ascii_art();
+
+        assert!(art.contains('Q'));
+        assert!(art.contains('♠'));
+        assert_eq!(art.lines().count(), 7);
+    }
+
+    #[test]
+    fn dealt_card_equals_rank_suit_tuple() {
+        use crate::deck::{Deck, DeckType};
+
+        let mut deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+        let card = deck.deal_top_card().unwrap();
+
+        assert!(card == (Rank::Ace, Suit::Hearts));
+        assert!(card != (Rank::Ace, Suit::Spades));
+    }
+
+    #[test]
+    fn numeric_rank_opt_is_none_for_jokers() {
+        let standard = CardKind::from(setup());
+        let joker = CardKind::from(Joker::new(Color::Red));
+
+        assert_eq!(standard.numeric_rank_opt(true), Some(14));
+        assert_eq!(joker.numeric_rank_opt(true), None);
+    }
+
+    #[test]
+    fn red_jokers_are_equal_and_display_color() {
+        let first = Joker::new(Color::Red);
+        let second = Joker::new(Color::Red);
+
+        assert_eq!(first, second);
+        assert_ne!(first, Joker::new(Color::Black));
+        assert_eq!(format!("{}", first), "Red Joker");
+    }
+
+    #[test]
+    fn ordered_cards_place_aces_by_flag() {
+        let cards: Vec<Card> = ["5S", "AH", "KD"]
+            .iter()
+            .map(|code| code.parse().unwrap())
+            .collect();
+        let sorted = |aces_high| {
+            let mut ordered: Vec<OrderedCard> = cards
+                .iter()
+                .map(|card| OrderedCard::new(card, aces_high))
+                .collect();
+            ordered.sort();
+            ordered
+                .iter()
+                .map(|card| card.card().short_code())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(sorted(true), ["5S", "KD", "AH"]);
+        assert_eq!(sorted(false), ["AH", "5S", "KD"]);
+    }
+
+    #[test]
+    fn from_indices_uses_values_order() {
+        assert_eq!(Card::from_indices(0, 0), Some(setup()));
+        assert_eq!(Card::from_indices(13, 0), None);
+        assert_eq!(Card::from_indices(0, 4), None);
+    }
+
+    #[test]
+    fn value_with_applies_baccarat_mapping() {
+        let baccarat = |rank: Rank| match rank {
+            Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => 0,
+            rank => rank.get_numerical_rank(false),
+        };
+
+        assert_eq!(Card::new(Rank::King, Suit::Clubs).value_with(baccarat), 0);
+        assert_eq!(Card::new(Rank::Ten, Suit::Clubs).value_with(baccarat), 0);
+        assert_eq!(setup().value_with(baccarat), 1);
+        assert_eq!(Card::new(Rank::Seven, Suit::Clubs).value_with(baccarat), 7);
+    }
+
+    #[test]
+    fn unicode_glyph_skips_knight() {
+        assert_eq!(Card::new(Rank::Ace, Suit::Spades).unicode_glyph(), '🂡');
+        assert_eq!(Card::new(Rank::Queen, Suit::Hearts).unicode_glyph(), '🂽');
+        assert_eq!(Card::new(Rank::Two, Suit::Diamonds).unicode_glyph(), '🃂');
+    }
+
+    #[test]
+    fn full_deck_fills_a_set() {
+        let cards: Vec<Card> = crate::deck::STANDARD_ORDER.to_vec();
+        let set: HashSet<Card> = cards.iter().copied().collect();
+
+        assert_eq!(set.len(), 52);
+        assert!(!has_duplicates(&cards));
+    }
+
+    #[test]
+    fn has_duplicates_finds_injected_copy() {
+        let mut cards: Vec<Card> = crate::deck::STANDARD_ORDER.to_vec();
+        cards.push(Card::new(Rank::Seven, Suit::Spades));
+
+        assert!(has_duplicates(&cards));
+        assert!(!has_duplicates(&[]));
+    }
 }
@@ -0,0 +1,147 @@
+//! A source of [Card]s that game code can deal from without knowing where they come from.
+//!
+//! Games written against `&mut dyn Dealer` work with a [Deck] or any custom
+//! source, such as [Card]s received over a network.
+
+use crate::card::Card;
+use crate::deck::{Deck, Finished};
+use crate::suit::Color;
+
+/// Anything that can deal [Card]s one at a time.
+pub trait Dealer {
+    /// Deals the next [Card], or `None` once the source is exhausted.
+    fn deal(&mut self) -> Option<Card>;
+}
+
+/// Deals from the end of the [Deck] configured with `deal_from`.
+impl Dealer for Deck<Finished> {
+    fn deal(&mut self) -> Option<Card> {
+        Deck::deal(self)
+    }
+}
+
+impl Deck<Finished> {
+    /// Wraps the [Deck] in a [ParityTracker] that tallies the [Color]s of dealt [Card]s.
+    pub fn parity_tracker(&mut self) -> ParityTracker<'_> {
+        ParityTracker {
+            deck: self,
+            balance: 0,
+        }
+    }
+}
+
+/// Deals from a borrowed [Deck], keeping a running balance of red against black [Card]s.
+///
+/// Dealing out a full standard [Deck] always brings the balance back to 0.
+#[derive(Debug)]
+pub struct ParityTracker<'a> {
+    deck: &'a mut Deck<Finished>,
+    balance: i32,
+}
+
+impl ParityTracker<'_> {
+    /// Get the number of red [Card]s dealt so far minus the number of black ones.
+    pub fn net_color_balance(&self) -> i32 {
+        self.balance
+    }
+}
+
+/// Deals from the wrapped [Deck], counting each red [Card] as +1 and each black as -1.
+impl Dealer for ParityTracker<'_> {
+    fn deal(&mut self) -> Option<Card> {
+        let card = self.deck.deal()?;
+        self.balance += match card.suit().color() {
+            Color::Red => 1,
+            Color::Black => -1,
+        };
+        Some(card)
+    }
+}
+
+/// Deals from an owned [Deck], logging every dealt [Card] so a demo can replay the deal.
+#[derive(Debug)]
+pub struct DemoDealer {
+    deck: Deck<Finished>,
+    log: Vec<Card>,
+}
+
+impl DemoDealer {
+    /// Wraps `deck` with an empty log.
+    pub fn new(deck: Deck<Finished>) -> DemoDealer {
+        DemoDealer {
+            deck,
+            log: Vec::new(),
+        }
+    }
+
+    /// Get every [Card] dealt so far, in the order they were dealt.
+    pub fn dealt_log(&self) -> &[Card] {
+        &self.log
+    }
+
+    /// Unwraps the [Deck], holding the [Card]s not yet dealt.
+    pub fn into_deck(self) -> Deck<Finished> {
+        self.deck
+    }
+}
+
+/// Deals from the wrapped [Deck], adding each [Card] to the log.
+impl Dealer for DemoDealer {
+    fn deal(&mut self) -> Option<Card> {
+        let card = self.deck.deal()?;
+        self.log.push(card);
+        Some(card)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deal_three(dealer: &mut dyn Dealer) -> Vec<Card> {
+        (0..3).filter_map(|_| dealer.deal()).collect()
+    }
+
+    #[test]
+    fn deck_deals_through_trait_object() {
+        let mut deck = Deck::default_new();
+        let expected = deck.peek_top_n(3);
+
+        let hand = deal_three(&mut deck);
+
+        assert_eq!(hand, expected);
+        assert_eq!(deck.total_cards(), 49);
+    }
+
+    #[test]
+    fn parity_returns_to_zero_over_full_deck() {
+        let mut deck = Deck::default_new();
+        let mut tracker = deck.parity_tracker();
+
+        let first = tracker.deal().unwrap();
+        assert_eq!(tracker.net_color_balance().abs(), 1);
+        assert_eq!(
+            first.suit().color() == Color::Red,
+            tracker.net_color_balance() == 1
+        );
+
+        while tracker.deal().is_some() {}
+        assert_eq!(tracker.net_color_balance(), 0);
+        assert_eq!(deck.total_cards(), 0);
+    }
+
+    #[test]
+    fn demo_dealer_logs_cards_in_deal_order() {
+        let deck = Deck::default_new();
+        let expected = deck.peek_top_n(5);
+        let mut demo = DemoDealer::new(deck);
+
+        let first = deal_three(&mut demo);
+        demo.deal();
+        demo.deal();
+
+        assert_eq!(first, expected[..3]);
+        assert_eq!(demo.dealt_log(), expected);
+        assert_eq!(demo.into_deck().total_cards(), 47);
+    }
+}
@@ -6,8 +6,16 @@
 //! `Deck::custom_new()` call to customize the [Deck] or `Deck::default_new()` to
 //! build a standard French deck.
 //!
+//! Positions in a [Deck] always count from the top, so index 0 is the top
+//! [Card], the next one dealt.
+//!
+//! A [Deck] holds standard [Card]s by default, but any type implementing
+//! [PlayingCard] can be dealt from one instead.
+//!
 //! # Examples
 //! ```
+//! use playing_cards::deck::{Deck, DeckType};
+//!
 //! // Create a new full 52 card deck and shuffle it 7 times
 //! let deck = Deck::custom_new().deck_type(DeckType::FullFrench).shuffle(7);
 //! assert_eq!(deck.total_cards(), 52);
@@ -17,38 +25,271 @@
 //! - [ ] Update documentation
 //!
 
-use crate::card::Card;
+use crate::card::{Card, CardKind, CardSet, Joker, OrderedCard, PlayingCard};
+use crate::error::CardError;
 use crate::rank::Rank;
-use crate::suit::Suit;
-use std::collections::VecDeque;
+use crate::shuffle::{self, ShuffleOp, ShuffleSequence};
+use crate::suit::{Color, Suit};
+use rand::distributions::WeightedIndex;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::{thread_rng, Rng, RngCore, SeedableRng};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Index, Range};
+
+/// Number of [Card]s in a Spider solitaire [Deck], whatever its number of [Suit]s.
+const SPIDER_SIZE: usize = 104;
+
+/// Every standard [Card] in the order of a freshly opened pack.
+///
+/// Grouped by [Suit] in the order of [Suit::VALUES], and within each [Suit] by
+/// [Rank] in the order of [Rank::VALUES]. This is the order of an unshuffled
+/// [DeckType::FullFrench] [Deck].
+pub const STANDARD_ORDER: [Card; 52] = all_standard_cards();
+
+/// Get every standard [Card] in the order of [STANDARD_ORDER].
+///
+/// Usable in constant expressions, such as building reference sets in tests.
+pub const fn all_standard_cards() -> [Card; 52] {
+    let mut cards = [Card::new(Rank::Ace, Suit::Hearts); 52];
+    let mut suit = 0;
+    while suit < Suit::VALUES.len() {
+        let mut rank = 0;
+        while rank < Rank::VALUES.len() {
+            let position = suit * Rank::VALUES.len() + rank;
+            cards[position] = Card::new(Rank::VALUES[rank], Suit::VALUES[suit]);
+            rank += 1;
+        }
+        suit += 1;
+    }
+    cards
+}
 
 /// A deck of playing cards.
-pub struct Deck<T: DeckState> {
-    cards: VecDeque<Card>,
+///
+/// Holds [Card]s by default, or any other [PlayingCard] such as [CardKind] for
+/// a [Deck] with Jokers.
+#[derive(Clone)]
+pub struct Deck<T: DeckState, C: PlayingCard = Card> {
+    cards: VecDeque<C>,
+    deal_end: DeckEnd,
+    rng: Option<StdRng>,
+    cut_card: Option<usize>,
+    shuffled: bool,
+    cyclic: bool,
+    history: Option<Vec<DeckOp>>,
+    dealt: usize,
+    fixed_top: usize,
+    deck_type: Option<DeckType>,
+    deck_copies: usize,
+    shuffle_count: usize,
+    max_size: Option<usize>,
+    aces_high: bool,
     state: PhantomData<T>,
 }
 
 /// Predefined types of [Deck]s.
+///
+/// More types are added over time, so matches outside this crate need a
+/// wildcard arm.
+///
+/// # Examples
+/// ```
+/// use playing_cards::deck::DeckType;
+///
+/// fn describe(deck_type: &DeckType) -> &'static str {
+///     match deck_type {
+///         DeckType::FullFrench => "52 card French deck",
+///         _ => "another deck",
+///     }
+/// }
+///
+/// assert_eq!(describe(&DeckType::FullFrench), "52 card French deck");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DeckType {
     FullFrench,
+    /// The 24 [Card]s from Nine to Ace in every [Suit].
+    Euchre,
+    /// Two copies of each [Card] from Nine to Ace in every [Suit], 48 in all.
+    Pinochle,
+    /// The 104 [Card]s of Spider solitaire, every [Rank] of `suits` [Suit]s repeated
+    /// as needed. `suits` must be 1 (Spades), 2 (Spades and Hearts) or 4.
+    Spider {
+        suits: u8,
+    },
+}
+
+impl DeckType {
+    /// Get the [Suit]s a Spider [Deck] of `suits` [Suit]s is built from, or `None`
+    /// if Spider is not played with that many.
+    fn spider_suits(suits: u8) -> Option<&'static [Suit]> {
+        match suits {
+            1 => Some(&[Suit::Spades]),
+            2 => Some(&[Suit::Spades, Suit::Hearts]),
+            4 => Some(&Suit::VALUES),
+            _ => None,
+        }
+    }
+}
+
+/// The end of a [Deck] that [Card]s are dealt from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckEnd {
+    Top,
+    Bottom,
+}
+
+/// The direction [Card]s travel around the table when dealing.
+///
+/// Seats are numbered in clockwise order, so dealing clockwise moves from each
+/// seat to the next higher one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DealDirection {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// An operation recorded in the history of a [Deck] built `with_history`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckOp {
+    /// A [Card] was dealt from the given end.
+    Deal(DeckEnd),
+    /// A [Card] was dealt from the given position, where the top is 0.
+    DealAt(usize),
+    /// The [Deck] was shuffled while it was built, or part of it with `shuffle_range`.
+    Shuffle,
+    /// The top given number of [Card]s were cut to the bottom.
+    Cut(usize),
+    /// The order of the [Deck] was reversed.
+    Reverse,
+    /// The top given number of [Card]s were split off into a separate [Deck].
+    SplitOff(usize),
+}
+
+/// Errors from dealing a hand of [Card]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DealError {
+    /// More [Card]s were requested than the game allows in one hand.
+    HandTooLarge { requested: usize, allowed: usize },
+    /// More [Card]s were requested than remain in the [Deck].
+    NotEnoughCards { requested: usize, remaining: usize },
+    /// The same [Card] would be dealt more than once, as can happen from a shoe.
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DealError::HandTooLarge { requested, allowed } => write!(
+                f,
+                "a hand of {} cards is larger than the {} allowed",
+                requested, allowed
+            ),
+            DealError::NotEnoughCards {
+                requested,
+                remaining,
+            } => write!(
+                f,
+                "cannot deal {} cards from a deck of {}",
+                requested, remaining
+            ),
+            DealError::DuplicateCard(card) => write!(f, "{} would be dealt more than once", card),
+        }
+    }
 }
 
+impl Error for DealError {}
+
 /// TypeState trait to control valid states of the [Deck].
-pub trait DeckState {}
+pub trait DeckState {
+    /// Name of the state, used when debugging a [Deck].
+    const NAME: &'static str;
+}
+
+/// Initial state of a [Deck] before any configuration.
+#[derive(Clone)]
+pub struct Start;
+
+/// State of a [Deck] while its [Card]s are being chosen.
+#[derive(Clone)]
+pub struct Building;
+
+/// State of a [Deck] whose [Card]s are built and are ready to be shuffled.
+#[derive(Clone)]
+pub struct Shuffling;
+
+/// State of a [Deck] that is ready to be dealt from.
+#[derive(Clone)]
+pub struct Finished;
+
+impl DeckState for Start {
+    const NAME: &'static str = "Start";
+}
+impl DeckState for Building {
+    const NAME: &'static str = "Building";
+}
+impl DeckState for Shuffling {
+    const NAME: &'static str = "Shuffling";
+}
+impl DeckState for Finished {
+    const NAME: &'static str = "Finished";
+}
 
-struct Start;
+impl<T: DeckState, C: PlayingCard> Deck<T, C> {
+    /// Moves the [Deck] into another [DeckState], keeping its configuration.
+    fn into_state<U: DeckState>(mut self) -> Deck<U, C> {
+        let cards = std::mem::take(&mut self.cards);
+        self.with_cards(cards)
+    }
 
-struct Building;
+    /// Moves the [Deck] into another [DeckState] holding the given [Card]s.
+    ///
+    /// Truncates the [Card]s to the cap set with `max_size`, if any.
+    fn with_cards<U: DeckState, D: PlayingCard>(self, mut cards: VecDeque<D>) -> Deck<U, D> {
+        if let Some(cap) = self.max_size {
+            cards.truncate(cap);
+        }
 
-struct Shuffling;
+        Deck {
+            cards,
+            dealt: self.dealt,
+            deal_end: self.deal_end,
+            rng: self.rng,
+            cut_card: self.cut_card,
+            shuffled: self.shuffled,
+            cyclic: self.cyclic,
+            history: self.history,
+            fixed_top: self.fixed_top,
+            deck_type: self.deck_type,
+            deck_copies: self.deck_copies,
+            shuffle_count: self.shuffle_count,
+            max_size: self.max_size,
+            aces_high: self.aces_high,
+            state: PhantomData,
+        }
+    }
 
-struct Finished;
+    /// Runs `f` over the [Card]s with the [Deck]'s RNG, falling back to `thread_rng`.
+    fn with_rng<R>(&mut self, f: impl FnOnce(&mut VecDeque<C>, &mut dyn RngCore) -> R) -> R {
+        match self.rng.as_mut() {
+            Some(rng) => f(&mut self.cards, rng),
+            None => f(&mut self.cards, &mut thread_rng()),
+        }
+    }
 
-impl DeckState for Start {}
-impl DeckState for Building {}
-impl DeckState for Shuffling {}
-impl DeckState for Finished {}
+    /// Adds `op` to the history, if the [Deck] keeps one.
+    fn record(&mut self, op: DeckOp) {
+        if let Some(history) = self.history.as_mut() {
+            history.push(op);
+        }
+    }
+}
 
 impl Deck<Start> {
     /// Begin creation of a custom [Deck].
@@ -56,30 +297,233 @@ impl Deck<Start> {
         Deck::new()
     }
 
+    /// Begin creation of a custom [Deck] that shuffles with an RNG seeded from `rng`.
+    ///
+    /// Two [Deck]s built from equally seeded RNGs with the same configuration
+    /// calls end up in the same order.
+    pub fn custom_new_with_rng<R: Rng>(mut rng: R) -> Deck<Building> {
+        let mut deck = Deck::new();
+        deck.rng = Some(StdRng::from_seed(rng.gen()));
+        deck
+    }
+
     /// Create a default playing card [Deck], shuffled 7 times.
     pub fn default_new() -> Deck<Finished> {
         Deck::new().deck_type(DeckType::FullFrench).shuffle(7)
     }
 
+    /// Create a default playing card [Deck], shuffled 7 times with an RNG seeded by `seed`.
+    ///
+    /// The reproducible sibling of `default_new`: the same seed always gives the same order.
+    pub fn default_new_seeded(seed: u64) -> Deck<Finished> {
+        let mut deck = Deck::new();
+        deck.rng = Some(StdRng::seed_from_u64(seed));
+        deck.deck_type(DeckType::FullFrench).shuffle(7)
+    }
+
+    /// Create a shuffled standard [Deck] without the `dealt` [Card]s, the [Card]s still unseen in a game.
+    ///
+    /// Shuffles 7 times with an RNG seeded from `rng`, so a seeded `rng` gives a
+    /// reproducible order.
+    pub fn standard_minus(dealt: &[Card], rng: &mut impl Rng) -> Deck<Finished> {
+        let unseen = STANDARD_ORDER
+            .into_iter()
+            .filter(|card| !dealt.contains(card));
+
+        Deck::custom_new_with_rng(rng)
+            .custom_cards(unseen)
+            .shuffle(7)
+    }
+
+    /// Create a shoe of `num_decks` copies of a [DeckType] stacked together, ready to shuffle.
+    ///
+    /// A shoe of 0 decks is deliberately empty rather than an error.
+    pub fn shoe(num_decks: usize, deck_type: DeckType) -> Deck<Shuffling> {
+        Deck::new().shoe_of(num_decks, deck_type)
+    }
+
+    /// Create an unshuffled [Deck] of every card in the [CardSet] `S`.
+    ///
+    /// The first card listed by the set is the top of the [Deck].
+    pub fn from_card_set<S: CardSet>() -> Deck<Finished, S::Card> {
+        Deck::new().with_cards(S::cards().into())
+    }
+
+    /// Create an unshuffled [Deck] from the bitset written by `to_bitset`.
+    ///
+    /// Bit `i` stands for the [Card] at position `i` of [STANDARD_ORDER]; bits
+    /// above the 52nd are ignored. The [Card]s are in [STANDARD_ORDER].
+    pub fn from_bitset(bits: u64) -> Deck<Finished> {
+        let cards = STANDARD_ORDER
+            .into_iter()
+            .enumerate()
+            .filter(|(position, _)| bits & (1 << position) != 0)
+            .map(|(_, card)| card)
+            .collect();
+
+        Deck::new().with_cards(cards)
+    }
+
+    /// Create an unshuffled [Deck] holding `cards`, where the first [Card] is the top.
+    ///
+    /// Accepts any [Card]s, including none or duplicates. See `try_from_cards`
+    /// for a validated [Deck].
+    pub fn from_cards(cards: Vec<Card>) -> Deck<Finished> {
+        Deck::new().with_cards(cards.into())
+    }
+
+    /// Create an unshuffled [Deck] holding `cards`, checking that it is usable.
+    ///
+    /// Returns [CardError::InvalidDeck] if `cards` is empty or, when `strict`, if
+    /// any [Card] appears more than once.
+    pub fn try_from_cards(cards: Vec<Card>, strict: bool) -> Result<Deck<Finished>, CardError> {
+        if cards.is_empty() {
+            return Err(CardError::InvalidDeck);
+        }
+
+        let deck = Deck::from_cards(cards);
+        if strict && deck.has_duplicates() {
+            return Err(CardError::InvalidDeck);
+        }
+
+        Ok(deck)
+    }
+
+    /// Reconstructs a [Deck] from the space separated short codes of `to_compact_string`.
+    ///
+    /// The first code is the top [Card] of the [Deck].
+    pub fn from_compact_string(s: &str) -> Result<Deck<Finished>, CardError> {
+        let cards = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<VecDeque<Card>, _>>()?;
+
+        Ok(Deck::new().with_cards(cards))
+    }
+
+    /// Builds a [Deck] from a list of [Card] short codes, such as `["AS", "KH"]`.
+    ///
+    /// The first code is the top [Card] of the [Deck].
+    pub fn from_short_codes(codes: &[&str]) -> Result<Deck<Finished>, CardError> {
+        let cards = codes
+            .iter()
+            .map(|code| code.parse())
+            .collect::<Result<VecDeque<Card>, _>>()?;
+
+        Ok(Deck::new().with_cards(cards))
+    }
+
     fn new() -> Deck<Building> {
         Deck {
             cards: VecDeque::new(),
+            deal_end: DeckEnd::Top,
+            rng: None,
+            cut_card: None,
+            shuffled: false,
+            cyclic: false,
+            history: None,
+            dealt: 0,
+            fixed_top: 0,
+            deck_type: None,
+            deck_copies: 1,
+            shuffle_count: 0,
+            max_size: None,
+            aces_high: true,
             state: PhantomData,
         }
     }
 }
 
 impl Deck<Building> {
+    /// Configure which end of the [Deck] `deal` takes [Card]s from. Defaults to [DeckEnd::Top].
+    pub fn deal_from(mut self, end: DeckEnd) -> Deck<Building> {
+        self.deal_end = end;
+        self
+    }
+
+    /// Configure the [Deck] to record each [DeckOp] applied to it, read back with `history`.
+    pub fn with_history(mut self) -> Deck<Building> {
+        self.history = Some(Vec::new());
+        self
+    }
+
+    /// Configure whether Aces rank high or low for `rank_value`. Defaults to high.
+    pub fn aces_high(mut self, high: bool) -> Deck<Building> {
+        self.aces_high = high;
+        self
+    }
+
+    /// Cap the [Deck] at `cap` [Card]s, protecting against accidentally enormous builds.
+    ///
+    /// Building more [Card]s than the cap keeps only the first `cap`, the ones
+    /// that would have been nearest the top before shuffling. A cap of 0 keeps
+    /// none, leaving the [Deck] empty.
+    pub fn max_size(mut self, cap: usize) -> Deck<Building> {
+        self.max_size = Some(cap);
+        self
+    }
+
+    /// Configure the [Deck] as a shoe of `num_decks` copies of a [DeckType], as `Deck::shoe` does.
+    ///
+    /// As with `Deck::shoe`, 0 decks build an empty [Deck].
+    pub fn shoe_of(self, num_decks: usize, deck_type: DeckType) -> Deck<Shuffling> {
+        let single = self.deck_type(deck_type);
+        let cards = single
+            .cards
+            .iter()
+            .copied()
+            .cycle()
+            .take(single.cards.len() * num_decks)
+            .collect();
+
+        let mut shoe = single.with_cards(cards);
+        shoe.deck_copies = num_decks;
+        shoe
+    }
+
     /// Configure the [Deck] as a provided custom [DeckType].
+    ///
+    /// # Panics
+    /// Panics for a Spider [DeckType] not of 1, 2 or 4 [Suit]s; use `try_deck_type`
+    /// to get an error instead.
     pub fn deck_type(self, deck_type: DeckType) -> Deck<Shuffling> {
-        let deck_size = match deck_type {
-            DeckType::FullFrench => 52,
+        // Euchre and Pinochle use Nine through Ace, which lead Rank::VALUES.
+        let (ranks, suits, copies): (&[Rank], &[Suit], usize) = match deck_type {
+            DeckType::FullFrench => (&Rank::VALUES, &Suit::VALUES, 1),
+            DeckType::Euchre => (&Rank::VALUES[..6], &Suit::VALUES, 1),
+            DeckType::Pinochle => (&Rank::VALUES[..6], &Suit::VALUES, 2),
+            DeckType::Spider { suits } => {
+                let suits =
+                    DeckType::spider_suits(suits).expect("Spider is played with 1, 2 or 4 suits");
+                let copies = SPIDER_SIZE / (Rank::VALUES.len() * suits.len());
+                (&Rank::VALUES, suits, copies)
+            }
         };
 
-        Deck {
-            cards: Deck::build_deck(deck_size, &Rank::VALUES, &Suit::VALUES),
-            state: PhantomData,
+        let set = Deck::build_deck(ranks.len() * suits.len(), ranks, suits);
+        let cards = set
+            .iter()
+            .copied()
+            .cycle()
+            .take(set.len() * copies)
+            .collect();
+        let mut deck = self.with_cards(cards);
+        deck.deck_type = Some(deck_type);
+        deck
+    }
+
+    /// Configure the [Deck] as with `deck_type`, rejecting [DeckType]s that cannot be built.
+    ///
+    /// Returns [CardError::InvalidDeck] for a Spider [DeckType] not of 1, 2
+    /// or 4 [Suit]s.
+    pub fn try_deck_type(self, deck_type: DeckType) -> Result<Deck<Shuffling>, CardError> {
+        if let DeckType::Spider { suits } = deck_type {
+            if DeckType::spider_suits(suits).is_none() {
+                return Err(CardError::InvalidDeck);
+            }
         }
+
+        Ok(self.deck_type(deck_type))
     }
 
     /// Pass in a slice of [Rank]s and [Suit]s to create a [Deck] with a custom set of [Card]s.
@@ -87,11 +531,63 @@ impl Deck<Building> {
     /// Each Rank will be applied with every Suit to create a product of all Ranks and Suits.
     /// Thus, if a deck with a double set of a Suit is required, the slice should have 2 instances
     /// of that Suit, and likewise with Ranks.
-    pub fn custom_deck_type(self, ranks: &[Rank], suits: &[Suit]) -> Deck<Shuffling> {
-        Deck {
-            cards: Deck::build_deck(ranks.len() * suits.len(), ranks, suits),
-            state: PhantomData,
+    ///
+    /// Returns [CardError::InvalidDeck] if either slice is empty, as the [Deck]
+    /// would have no [Card]s.
+    pub fn custom_deck_type(
+        self,
+        ranks: &[Rank],
+        suits: &[Suit],
+    ) -> Result<Deck<Shuffling>, CardError> {
+        if ranks.is_empty() || suits.is_empty() {
+            return Err(CardError::InvalidDeck);
         }
+
+        let cards = Deck::build_deck(ranks.len() * suits.len(), ranks, suits);
+        Ok(self.with_cards(cards))
+    }
+
+    /// Create a [Deck] from any set of [PlayingCard]s, such as a custom card type.
+    ///
+    /// The first [PlayingCard] given is the top of the [Deck] before shuffling.
+    pub fn custom_cards<C: PlayingCard>(
+        self,
+        cards: impl IntoIterator<Item = C>,
+    ) -> Deck<Shuffling, C> {
+        self.with_cards(cards.into_iter().collect())
+    }
+
+    /// Create a [Deck] of every standard [Rank] in only the listed [Suit]s.
+    ///
+    /// Returns [CardError::InvalidDeck] if no [Suit]s are given.
+    pub fn only_suits(self, suits: &[Suit]) -> Result<Deck<Shuffling>, CardError> {
+        self.custom_deck_type(&Rank::VALUES, suits)
+    }
+
+    /// Create a [Deck] of every [Rank] from `low` to `high` inclusive, in every [Suit].
+    ///
+    /// [Rank]s are compared by their numerical value, so whether Aces are high decides
+    /// which end of the range they fall on. Returns [CardError::InvalidDeck] if `low`
+    /// is above `high`.
+    pub fn rank_range(
+        self,
+        low: Rank,
+        high: Rank,
+        aces_high: bool,
+    ) -> Result<Deck<Shuffling>, CardError> {
+        let (low, high) = (
+            low.get_numerical_rank(aces_high),
+            high.get_numerical_rank(aces_high),
+        );
+        if low > high {
+            return Err(CardError::InvalidDeck);
+        }
+
+        let ranks: Vec<Rank> = Rank::VALUES
+            .into_iter()
+            .filter(|rank| (low..=high).contains(&rank.get_numerical_rank(aces_high)))
+            .collect();
+        self.custom_deck_type(&ranks, &Suit::VALUES)
     }
 
     fn build_deck(capacity: usize, ranks: &[Rank], suits: &[Suit]) -> VecDeque<Card> {
@@ -108,77 +604,2874 @@ impl Deck<Building> {
 }
 
 impl Deck<Shuffling> {
+    /// Fixes `cards` to the top of the [Deck], so shuffling only mixes the [Card]s beneath them.
+    ///
+    /// The first [Card] given is dealt first. Each [Card] is taken from among the
+    /// unfixed [Card]s if present, so the [Deck] keeps its composition, and added
+    /// otherwise. Fixing more [Card]s later puts them above those already fixed.
+    pub fn fix_top(mut self, cards: Vec<Card>) -> Deck<Shuffling> {
+        for card in &cards {
+            let position = self
+                .cards
+                .iter()
+                .skip(self.fixed_top)
+                .position(|other| other == card);
+            if let Some(position) = position {
+                self.cards.remove(self.fixed_top + position);
+            }
+        }
+
+        for card in cards.iter().rev() {
+            self.cards.push_front(*card);
+        }
+        self.fixed_top += cards.len();
+        self
+    }
+
+    /// Adds one red and one black [Joker] to the bottom of the [Deck] before shuffling.
+    ///
+    /// A full French deck becomes the 54 card deck used by many games.
+    pub fn with_jokers(self) -> Deck<Shuffling, CardKind> {
+        self.with_joker_count(2)
+    }
+
+    /// Adds `count` [Joker]s to the bottom of the [Deck], alternating red and black.
+    pub fn with_joker_count(mut self, count: usize) -> Deck<Shuffling, CardKind> {
+        let jokers = [Color::Red, Color::Black]
+            .into_iter()
+            .cycle()
+            .take(count)
+            .map(|color| CardKind::from(Joker::new(color)));
+
+        let cards = std::mem::take(&mut self.cards)
+            .into_iter()
+            .map(CardKind::from)
+            .chain(jokers)
+            .collect();
+
+        self.with_cards(cards)
+    }
+}
+
+impl<C: PlayingCard> Deck<Shuffling, C> {
     /// Shuffles the [Deck] anywhere from 1 to 10 times.
-    pub fn shuffle(mut self, shuffles: usize) -> Deck<Finished> {
-        use rand::seq::SliceRandom;
-        use rand::thread_rng;
+    pub fn shuffle(self, shuffles: usize) -> Deck<Finished, C> {
+        // Counts beyond the supported range fall back to a single shuffle.
+        let count = match shuffles {
+            1..=10 => shuffles,
+            _ => 1,
+        };
 
-        let cards = self.cards.make_contiguous();
+        self.finish_shuffle(count, |deck| {
+            deck.with_rng(|cards, rng| {
+                let cards = cards.make_contiguous();
+
+                match shuffles {
+                    1..=10 => {
+                        for _ in 0..=shuffles {
+                            cards.shuffle(rng);
+                        }
+                    }
+                    _ => cards.shuffle(rng),
+                }
+            });
+
+            let halfway = deck.cards.len() / 2;
+            deck.cards.rotate_left(halfway);
+        })
+    }
+
+    /// Repeats the [Card]s built so far until the [Deck] holds `copies` of each.
+    ///
+    /// Two copies of a Euchre [Deck] make a Pinochle [Deck], and four a double
+    /// Pinochle [Deck]. The copies follow one another in the built order, and 0
+    /// copies empty the [Deck] without an error.
+    pub fn duplicate_each(self, copies: usize) -> Deck<Shuffling, C> {
+        let cards = self
+            .cards
+            .iter()
+            .copied()
+            .cycle()
+            .take(self.cards.len() * copies)
+            .collect();
+
+        let mut deck = self.with_cards(cards);
+        deck.deck_copies *= copies;
+        deck
+    }
+
+    /// Shuffles the [Deck] as with `shuffle`, using an RNG seeded by `seed`.
+    ///
+    /// Replaces any RNG given when the [Deck] was created, so equally built
+    /// [Deck]s shuffled with the same seed end up in the same order.
+    pub fn shuffle_seeded(mut self, shuffles: usize, seed: u64) -> Deck<Finished, C> {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+        self.shuffle(shuffles)
+    }
 
-        match shuffles {
-            1..=10 => {
-                for _ in 0..=shuffles {
-                    cards.shuffle(&mut thread_rng());
+    /// Shuffles the [Deck] as with `shuffle` from a random seed, returning the seed used.
+    ///
+    /// Passing the seed to `shuffle_seeded` on an equally built [Deck] replays the shuffle.
+    pub fn shuffle_recording(self, shuffles: usize) -> (Deck<Finished, C>, u64) {
+        let seed = thread_rng().gen();
+        (self.shuffle_seeded(shuffles, seed), seed)
+    }
+
+    /// Washes the [Deck] like a fresh casino pack, swapping two random [Card]s `iterations` times.
+    ///
+    /// Uses `rng` rather than the [Deck]'s own RNG, so a seeded `rng` gives a
+    /// reproducible order. Several hundred swaps thoroughly mix a 52 [Card] [Deck].
+    pub fn wash(self, rng: &mut impl Rng, iterations: usize) -> Deck<Finished, C> {
+        self.finish_shuffle(usize::from(iterations > 0), |deck| {
+            let len = deck.cards.len();
+            if len > 1 {
+                for _ in 0..iterations {
+                    deck.cards
+                        .swap(rng.gen_range(0, len), rng.gen_range(0, len));
                 }
             }
-            _ => cards.shuffle(&mut thread_rng()),
-        }
+        })
+    }
+
+    /// Lightly mixes the [Deck] by swapping `swaps` random pairs of neighbouring [Card]s.
+    ///
+    /// Leaves the [Deck] close to its built order, for testing how code copes with
+    /// a controlled amount of disorder. Uses `rng`, as with `wash`.
+    pub fn partial_shuffle_with<R: Rng>(self, swaps: usize, rng: &mut R) -> Deck<Finished, C> {
+        self.finish_shuffle(usize::from(swaps > 0), |deck| {
+            let len = deck.cards.len();
+            if len > 1 {
+                for _ in 0..swaps {
+                    let i = rng.gen_range(0, len - 1);
+                    deck.cards.swap(i, i + 1);
+                }
+            }
+        })
+    }
+
+    /// Imperfectly shuffles the [Deck] like a lazy dealer, keeping more of its order as `bias` rises.
+    ///
+    /// Runs a Fisher-Yates shuffle where each [Card] swaps with one at most a
+    /// `1 - bias` share of the [Deck] above it. A `bias` of 0 shuffles fully at
+    /// random and 1 leaves the order untouched; values outside that range are
+    /// clamped. Uses `rng`, as with `wash`.
+    pub fn biased_shuffle(self, bias: f64, rng: &mut impl Rng) -> Deck<Finished, C> {
+        let share = 1.0 - bias.clamp(0.0, 1.0);
+        let reach = (share * self.cards.len().saturating_sub(1) as f64).round() as usize;
 
-        let halfway = cards.len() / 2;
+        self.finish_shuffle(usize::from(reach > 0), |deck| {
+            for i in (1..deck.cards.len()).rev() {
+                let j = rng.gen_range(i.saturating_sub(reach), i + 1);
+                deck.cards.swap(i, j);
+            }
+        })
+    }
 
-        let (first, last) = cards.split_at(halfway);
+    /// Riffle shuffles the [Deck] `times` times, as an alternative to `shuffle`.
+    ///
+    /// Seven riffles are enough to thoroughly mix a 52 [Card] [Deck].
+    pub fn riffle(self, times: usize) -> Deck<Finished, C> {
+        self.apply_shuffles(&vec![ShuffleOp::Riffle; times])
+    }
 
-        let cards: VecDeque<Card> = [last, first].concat().into();
+    /// Begin a [ShuffleSequence] to apply several [ShuffleOp]s in order.
+    pub fn shuffle_sequence(self) -> ShuffleSequence<C> {
+        ShuffleSequence::new(self)
+    }
 
-        Deck {
-            cards,
-            state: PhantomData,
-        }
+    /// Applies each [ShuffleOp] to the [Deck] in order.
+    pub(crate) fn apply_shuffles(self, ops: &[ShuffleOp]) -> Deck<Finished, C> {
+        self.finish_shuffle(ops.len(), |deck| {
+            for op in ops {
+                match op {
+                    ShuffleOp::Riffle => deck.with_rng(shuffle::riffle),
+                    ShuffleOp::Overhand => deck.with_rng(shuffle::overhand),
+                    ShuffleOp::Cut(at) => shuffle::cut(&mut deck.cards, *at),
+                }
+            }
+        })
     }
 
     /// Returns the [Deck] as it was created in the [Building] phase.
-    pub fn no_shuffle(self) -> Deck<Finished> {
-        Deck {
-            cards: self.cards,
-            state: PhantomData,
+    pub fn no_shuffle(self) -> Deck<Finished, C> {
+        self.into_state()
+    }
+
+    /// Runs `shuffle` over the [Card]s beneath any fixed with `fix_top`, then finishes the [Deck].
+    ///
+    /// `shuffles` is the number of shuffles `shuffle` performs, as reported by `build_summary`.
+    fn finish_shuffle(
+        mut self,
+        shuffles: usize,
+        shuffle: impl FnOnce(&mut Self),
+    ) -> Deck<Finished, C> {
+        let rest = self.cards.split_off(self.fixed_top.min(self.cards.len()));
+        let top = std::mem::replace(&mut self.cards, rest);
+        shuffle(&mut self);
+        let rest = std::mem::replace(&mut self.cards, top);
+        self.cards.extend(rest);
+
+        self.shuffled = shuffles > 0;
+        self.shuffle_count += shuffles;
+        if self.shuffled {
+            self.record(DeckOp::Shuffle);
         }
+        self.into_state()
     }
 }
 
-impl Deck<Finished> {
+impl<C: PlayingCard> Deck<Finished, C> {
+    /// Deals a [Card] from the end of the [Deck] configured with `deal_from`.
+    pub fn deal(&mut self) -> Option<C> {
+        match self.deal_end {
+            DeckEnd::Top => self.deal_top_card(),
+            DeckEnd::Bottom => self.deal_bottom_card(),
+        }
+    }
+
     /// Deals the top [Card] from the [Deck].
-    pub fn deal_top_card(&mut self) -> Option<Card> {
-        self.cards.pop_front()
+    ///
+    /// In a cyclic [Deck] a copy of the dealt [Card] returns to the bottom.
+    pub fn deal_top_card(&mut self) -> Option<C> {
+        self.take_at(0, DeckOp::Deal(DeckEnd::Top))
     }
 
-    /// Deals the bottom [Card] from the [Deck].
-    pub fn deal_bottom_card(&mut self) -> Option<Card> {
-        self.cards.pop_back()
+    /// Deals `n` [Card]s from the top, face up into a shared area such as a community board.
+    ///
+    /// Dealing is the same as calling `deal_top_card` `n` times; the name marks the
+    /// [Card]s as revealed to every player. Deals fewer than `n` [Card]s if the [Deck]
+    /// runs short.
+    pub fn deal_face_up(&mut self, n: usize) -> Vec<C> {
+        std::iter::from_fn(|| self.deal_top_card())
+            .take(n)
+            .collect()
     }
 
-    /// Get the all [Card]s left in the [Deck].
-    pub fn total_cards(&self) -> usize {
-        self.cards.len()
+    /// Returns whether at least `n` [Card]s can be dealt without running out.
+    ///
+    /// A cyclic [Deck] holding any [Card]s can always deal.
+    pub fn can_deal(&self, n: usize) -> bool {
+        self.cards.len() >= n || (self.cyclic && !self.cards.is_empty())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Deals exactly `n` [Card]s with `deal`, or returns `None` without dealing any.
+    pub fn deal_exact(&mut self, n: usize) -> Option<Vec<C>> {
+        if !self.can_deal(n) {
+            return None;
+        }
 
-    #[test]
-    fn default_new_returns_52_card_deck() {
-        let deck = Deck::default_new();
+        (0..n).map(|_| self.deal()).collect()
+    }
 
-        assert_eq!(deck.total_cards(), 52)
+    /// Deals `cards_each` [Card]s with `deal` to every player of every team, nested team → player → [Card]s.
+    ///
+    /// Teammates sit alternately, so with two teams the seating is team 0's first
+    /// player, team 1's first player, team 0's second player and so on. [Card]s
+    /// go one at a time around the table, and dealing stops early if the [Deck]
+    /// runs out.
+    pub fn deal_to_teams(
+        &mut self,
+        teams: usize,
+        players_per_team: usize,
+        cards_each: usize,
+    ) -> Vec<Vec<Vec<C>>> {
+        let mut dealt = vec![vec![Vec::with_capacity(cards_each); players_per_team]; teams];
+
+        'rounds: for _ in 0..cards_each {
+            for seat in 0..teams * players_per_team {
+                match self.deal() {
+                    Some(card) => dealt[seat % teams][seat / teams].push(card),
+                    None => break 'rounds,
+                }
+            }
+        }
+
+        dealt
     }
 
-    #[test]
-    fn custom_new_returns_52_card_deck() {
-        let deck = Deck::custom_new()
-            .deck_type(DeckType::FullFrench)
-            .shuffle(7);
+    /// Deals `cards_each` [Card]s with `deal` to each of `players` seats, one at a time.
+    ///
+    /// The first [Card] goes to seat `start`, then around the table in
+    /// `direction`. The result is indexed by seat. Dealing stops early if the
+    /// [Deck] runs out, and with 0 players nothing is dealt.
+    pub fn deal_around(
+        &mut self,
+        players: usize,
+        direction: DealDirection,
+        start: usize,
+        cards_each: usize,
+    ) -> Vec<Vec<C>> {
+        let mut hands = vec![Vec::with_capacity(cards_each); players];
+        if players == 0 {
+            return hands;
+        }
 
-        assert_eq!(deck.total_cards(), 52)
+        let step = match direction {
+            DealDirection::Clockwise => 1,
+            DealDirection::CounterClockwise => players - 1,
+        };
+        let mut seat = start % players;
+        for _ in 0..players * cards_each {
+            match self.deal() {
+                Some(card) => hands[seat].push(card),
+                None => break,
+            }
+            seat = (seat + step) % players;
+        }
+
+        hands
+    }
+
+    /// Inserts `card` at a uniformly random position, anywhere from the top to the bottom.
+    pub fn insert_random(&mut self, card: C, rng: &mut impl Rng) {
+        let position = rng.gen_range(0, self.cards.len() + 1);
+        if let Some(below) = self.cut_card {
+            if self.cards.len() - position <= below {
+                self.add_beneath_cut_card(1);
+            }
+        }
+        self.cards.insert(position, card);
+    }
+
+    /// Moves every [Card] out of `cards` onto the bottom of the [Deck], such as a discard pile.
+    ///
+    /// The [Card]s keep their order and `cards` is left empty, keeping its capacity
+    /// for reuse.
+    pub fn refill_from(&mut self, cards: &mut Vec<C>) {
+        self.add_beneath_cut_card(cards.len());
+        self.cards.extend(cards.drain(..));
+    }
+
+    /// Returns dealt `hands` to the bottom of the [Deck] in the order they were dealt round-robin.
+    ///
+    /// Takes the first [Card] of each hand in turn, then the second, and so on,
+    /// skipping hands that have run out. Gathering what `deal_alternating` dealt
+    /// restores the [Deck]'s order before the deal.
+    pub fn gather_in_order(&mut self, hands: &[Vec<C>]) {
+        let rounds = hands.iter().map(Vec::len).max().unwrap_or(0);
+        self.add_beneath_cut_card(hands.iter().map(Vec::len).sum());
+        for round in 0..rounds {
+            self.cards
+                .extend(hands.iter().filter_map(|hand| hand.get(round)).copied());
+        }
+    }
+
+    /// Deals the whole [Deck] one [Card] at a time to each of `piles` piles in turn.
+    ///
+    /// The first pile receives the top [Card]; piles differ in size by at most
+    /// one. With 0 piles nothing is dealt and an empty list is returned.
+    pub fn deal_alternating(&mut self, piles: usize) -> Vec<Vec<C>> {
+        if piles == 0 {
+            return Vec::new();
+        }
+
+        let mut dealt = vec![Vec::with_capacity(self.cards.len().div_ceil(piles)); piles];
+        for (position, card) in self.take_all().into_iter().enumerate() {
+            dealt[position % piles].push(card);
+        }
+
+        dealt
+    }
+
+    /// Deals the top [Card] along with the number of [Card]s left after dealing it.
+    pub fn deal_top_with_count(&mut self) -> Option<(C, usize)> {
+        let card = self.deal_top_card()?;
+        Some((card, self.cards.len()))
+    }
+
+    /// Look at the top [Card] of the [Deck] without dealing it.
+    pub fn peek_top(&self) -> Option<&C> {
+        self.cards.front()
+    }
+
+    /// Look at the bottom [Card] of the [Deck] without dealing it.
+    pub fn peek_bottom(&self) -> Option<&C> {
+        self.cards.back()
+    }
+
+    /// Deals the top [Card] from the [Deck], treating an empty [Deck] as an error.
+    pub fn try_deal_top(&mut self) -> Result<C, CardError> {
+        self.deal_top_card().ok_or(CardError::EmptyDeck)
+    }
+
+    /// Deals the bottom [Card] from the [Deck], treating an empty [Deck] as an error.
+    pub fn try_deal_bottom(&mut self) -> Result<C, CardError> {
+        self.deal_bottom_card().ok_or(CardError::EmptyDeck)
+    }
+
+    /// Deals the bottom [Card] from the [Deck].
+    ///
+    /// In a cyclic [Deck] a copy of the dealt [Card] returns to the top.
+    pub fn deal_bottom_card(&mut self) -> Option<C> {
+        let bottom = self.cards.len().checked_sub(1)?;
+        self.take_at(bottom, DeckOp::Deal(DeckEnd::Bottom))
+    }
+
+    /// Deals the top and bottom [Card]s together, returned as `(top, bottom)`.
+    ///
+    /// A [Deck] holding a single [Card] deals it as the top, with no bottom [Card].
+    /// In a cyclic [Deck] the top [Card] returns to the bottom and the bottom
+    /// [Card] to the top.
+    pub fn deal_ends(&mut self) -> (Option<C>, Option<C>) {
+        let single = self.cards.len() == 1;
+        let top = self.deal_top_card();
+        if single {
+            return (top, None);
+        }
+
+        if !self.cyclic {
+            return (top, self.deal_bottom_card());
+        }
+
+        // The top Card is now on the bottom, so deal from beneath it.
+        let recycled = self.cards.pop_back();
+        let bottom = self.deal_bottom_card();
+        self.cards.extend(recycled);
+
+        (top, bottom)
+    }
+
+    /// Deals the first [Card] from the top that satisfies `pred`, leaving the others in place.
+    pub fn deal_first_matching(&mut self, pred: impl Fn(&C) -> bool) -> Option<C> {
+        let position = self.cards.iter().position(pred)?;
+        self.take_at(position, DeckOp::DealAt(position))
+    }
+
+    /// Deals the first [Card] from the top that is not in `excluded`.
+    ///
+    /// Excluded [Card]s passed over stay where they are. Returns `None` if only
+    /// excluded [Card]s remain.
+    pub fn deal_top_except(&mut self, excluded: &[C]) -> Option<C> {
+        self.deal_first_matching(|card| !excluded.contains(card))
+    }
+
+    /// Makes the [Deck] cyclic, so dealt [Card]s are recycled and it never runs out.
+    ///
+    /// [Card]s dealt from the bottom return to the top, and all others to the bottom.
+    /// Only `remove_card` still takes a [Card] out for good.
+    pub fn make_cyclic(&mut self) {
+        self.cyclic = true;
+    }
+
+    /// Deals a [Card] chosen with probability proportional to its weight.
+    ///
+    /// [Card]s with a weight of 0 are never drawn. Returns `None` if the [Deck]
+    /// is empty, any weight is negative, or every weight is 0.
+    pub fn deal_weighted(&mut self, weights: impl Fn(&C) -> f64, rng: &mut impl Rng) -> Option<C> {
+        let distribution = WeightedIndex::new(self.cards.iter().map(weights)).ok()?;
+        let position = rng.sample(distribution);
+
+        self.take_at(position, DeckOp::DealAt(position))
+    }
+
+    /// Get the position from the top of the first [Card] equal to `card`, where the top is 0.
+    pub fn position_of(&self, card: &C) -> Option<usize> {
+        self.cards.iter().position(|other| other == card)
+    }
+
+    /// Returns whether `card` is still in the [Deck].
+    pub fn remaining_contains(&self, card: &C) -> bool {
+        self.cards.contains(card)
+    }
+
+    /// Removes the first copy of `card` from wherever it is in the [Deck].
+    ///
+    /// The other [Card]s keep their order, and even a cyclic [Deck] does not get
+    /// `card` back. Returns `None` if `card` is not in the [Deck].
+    pub fn remove_card(&mut self, card: &C) -> Option<C> {
+        let position = self.position_of(card)?;
+        self.take_out(position, DeckOp::DealAt(position))
+    }
+
+    /// Places a cut card in the [Deck] with `from_bottom` [Card]s beneath it.
+    ///
+    /// Once dealing reaches the cut card, `needs_reshuffle` reports true. [Card]s
+    /// later added to the bottom, as with `refill_from`, go beneath the cut card.
+    /// Inserting a new cut card replaces the previous one.
+    pub fn insert_cut_card(&mut self, from_bottom: usize) {
+        self.cut_card = Some(from_bottom);
+    }
+
+    /// Returns whether dealing has reached the cut card, signalling a reshuffle.
+    pub fn needs_reshuffle(&self) -> bool {
+        match self.cut_card {
+            Some(below) => self.cards.len() <= below,
+            None => false,
+        }
+    }
+
+    /// Borrow up to `k` [Card]s from the top of the [Deck] without dealing them.
+    ///
+    /// Returns fewer than `k` [Card]s if the [Deck] runs short. Takes `&mut self`
+    /// as the underlying buffer may need to be made contiguous first.
+    pub fn top_k(&mut self, k: usize) -> &[C] {
+        let cards = self.cards.make_contiguous();
+        &cards[..k.min(cards.len())]
+    }
+
+    /// Copy the [Card]s at positions within `range`, where the top is 0.
+    ///
+    /// Bounds beyond the [Deck] are clamped to its size.
+    pub fn peek_range(&self, range: Range<usize>) -> Vec<C> {
+        let end = range.end.min(self.cards.len());
+        let start = range.start.min(end);
+
+        self.cards.range(start..end).copied().collect()
+    }
+
+    /// Iterate over the [Card]s left in the [Deck], from top to bottom.
+    pub fn iter(&self) -> impl Iterator<Item = &C> {
+        self.cards.iter()
+    }
+
+    /// Iterate over the [Card]s left in the [Deck], from top to bottom, like `iter`.
+    ///
+    /// Never mutates the [Deck] or its buffer, unlike `top_k` and `as_card_slice`,
+    /// which may need to make it contiguous first.
+    pub fn front_to_back(&self) -> impl Iterator<Item = &C> {
+        self.cards.iter()
+    }
+
+    /// Iterate over the [Card]s left in the [Deck] with their positions, where the top is 0.
+    pub fn enumerate_iter(&self) -> impl Iterator<Item = (usize, &C)> {
+        self.iter().enumerate()
+    }
+
+    /// Borrow every [Card] left in the [Deck] as a slice, top [Card] first.
+    ///
+    /// The [Deck] cannot implement `AsRef<[Card]>`, as its ring buffer may need to
+    /// be made contiguous first, which needs `&mut self`.
+    pub fn as_card_slice(&mut self) -> &[C] {
+        self.cards.make_contiguous()
+    }
+
+    /// Lay out the [Card]s left in rows of `columns`, reading from the top.
+    ///
+    /// The last row holds any leftover [Card]s. Zero `columns` gives no rows.
+    pub fn as_grid(&self, columns: usize) -> Vec<Vec<C>> {
+        if columns == 0 {
+            return Vec::new();
+        }
+
+        let cards: Vec<C> = self.cards.iter().copied().collect();
+        cards.chunks(columns).map(|row| row.to_vec()).collect()
+    }
+
+    /// Copy up to `n` [Card]s from the top of the [Deck] without dealing them.
+    ///
+    /// Unlike `top_k` this only needs `&self`, at the cost of copying the [Card]s.
+    pub fn peek_top_n(&self, n: usize) -> Vec<C> {
+        self.cards.iter().take(n).copied().collect()
+    }
+
+    /// Cuts the [Deck], moving the top `at` [Card]s to the bottom in order.
+    ///
+    /// Cutting at or beyond the size of the [Deck] leaves it unchanged.
+    pub fn cut(&mut self, at: usize) {
+        shuffle::cut(&mut self.cards, at);
+        self.record(DeckOp::Cut(at));
+    }
+
+    /// Cuts the [Deck] so the first [Card] equal to `target` ends up on top.
+    ///
+    /// Returns whether `target` was found; if not, the [Deck] is left unchanged.
+    pub fn cut_to_card(&mut self, target: C) -> bool {
+        match self.position_of(&target) {
+            Some(position) => {
+                self.cut(position);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Rotates the [Deck], moving the top `n` [Card]s to the bottom in order.
+    ///
+    /// Unlike `cut`, rotating by more than the size of the [Deck] wraps around,
+    /// so rotating by the size of the [Deck] leaves it unchanged.
+    pub fn rotate(&mut self, n: usize) {
+        if !self.cards.is_empty() {
+            let len = self.cards.len();
+            self.cut(n % len);
+        }
+    }
+
+    /// Cuts the [Deck] at a random position, so at least one [Card] moves.
+    ///
+    /// A [Deck] with fewer than two [Card]s is left unchanged.
+    pub fn cut_randomly(&mut self, rng: &mut impl Rng) {
+        if self.cards.len() < 2 {
+            return;
+        }
+
+        let at = rng.gen_range(1, self.cards.len());
+        self.cut(at);
+    }
+
+    /// Shuffles only the [Card]s at positions within `range`, where the top is 0.
+    ///
+    /// Bounds beyond the [Deck] are clamped to its size; [Card]s outside the
+    /// range keep their positions.
+    pub fn shuffle_range(&mut self, range: Range<usize>, rng: &mut impl Rng) {
+        let cards = self.cards.make_contiguous();
+        let end = range.end.min(cards.len());
+        let start = range.start.min(end);
+
+        cards[start..end].shuffle(rng);
+        self.record(DeckOp::Shuffle);
+    }
+
+    /// Reverses the order of the [Deck], so the bottom [Card] becomes the top.
+    ///
+    /// Afterwards index 0 holds what was the bottom [Card].
+    pub fn reverse(&mut self) {
+        self.cards.make_contiguous().reverse();
+        self.record(DeckOp::Reverse);
+    }
+
+    /// Sorts the [Deck] with `compare`, so the least [Card] is on top.
+    ///
+    /// The sort is stable, so [Card]s that compare equal keep their order.
+    pub fn sort_by<F: FnMut(&C, &C) -> Ordering>(&mut self, compare: F) {
+        self.cards.make_contiguous().sort_by(compare);
+    }
+
+    /// Consumes the [Deck], returning a new [Deck] of only the [Card]s matching `pred`.
+    ///
+    /// The [Card]s keep their relative order, and the new [Deck] counts none as dealt.
+    pub fn filter_into(mut self, pred: impl Fn(&C) -> bool) -> Deck<Finished, C> {
+        let cards = std::mem::take(&mut self.cards)
+            .into_iter()
+            .filter(|card| pred(card))
+            .collect();
+
+        self.dealt = 0;
+        self.with_cards(cards)
+    }
+
+    /// Removes the top `n` [Card]s and returns them as a new [Deck], in the same order.
+    ///
+    /// Takes every [Card] if fewer than `n` remain. The new [Deck] starts with
+    /// default settings rather than copying this one's.
+    pub fn split_off_top(&mut self, n: usize) -> Deck<Finished, C> {
+        let rest = self.cards.split_off(n.min(self.cards.len()));
+        let top = std::mem::replace(&mut self.cards, rest);
+        self.record(DeckOp::SplitOff(top.len()));
+
+        Deck::new().with_cards(top)
+    }
+
+    /// Splits the [Deck] into two piles: the top `at` [Card]s, and the rest.
+    ///
+    /// Both piles keep their order, and `at` is clamped to the size of the [Deck].
+    /// As with `split_off_top`, the top pile starts with default settings while
+    /// the rest keeps this [Deck]'s.
+    pub fn split(mut self, at: usize) -> (Deck<Finished, C>, Deck<Finished, C>) {
+        let top = self.split_off_top(at);
+        (top, self)
+    }
+
+    /// Places every [Card] of `other` on top of this [Deck], keeping `other`'s order.
+    pub fn stack_on_top(&mut self, mut other: Deck<Finished, C>) {
+        other.cards.append(&mut self.cards);
+        self.cards = other.cards;
+    }
+
+    /// Places every [Card] of `other` beneath this [Deck], keeping `other`'s order.
+    pub fn stack_on_bottom(&mut self, mut other: Deck<Finished, C>) {
+        self.add_beneath_cut_card(other.cards.len());
+        self.cards.append(&mut other.cards);
+    }
+
+    /// Perfectly interleaves this [Deck] with `other`, one [Card] at a time.
+    ///
+    /// The top [Card] of this [Deck] stays on top, followed by the top [Card] of
+    /// `other`. Leftover [Card]s from the longer [Deck] go to the bottom in order.
+    pub fn faro(mut self, other: Deck<Finished, C>) -> Deck<Finished, C> {
+        let cards = std::mem::take(&mut self.cards);
+        self.cards = shuffle::interleave(cards, other.cards);
+        self
+    }
+
+    /// Performs a perfect out-faro: splits the [Deck] in half and interleaves the halves.
+    ///
+    /// The top [Card] stays on top, and with an odd count the top half holds
+    /// the extra [Card]. Eight out-faros return a 52 [Card] [Deck] to its
+    /// original order.
+    pub fn faro_self(mut self) -> Deck<Finished, C> {
+        let halfway = self.cards.len().div_ceil(2);
+        let bottom = self.cards.split_off(halfway);
+        let top = std::mem::take(&mut self.cards);
+
+        self.cards = shuffle::interleave(top, bottom);
+        self
+    }
+
+    /// Deals `trials` hands of up to `hand_size` [Card]s, each from a freshly shuffled copy of the [Deck].
+    ///
+    /// The [Deck] itself is left untouched, which makes this handy for probability experiments.
+    pub fn sample_hands(&self, hand_size: usize, trials: usize, rng: &mut impl Rng) -> Vec<Vec<C>> {
+        (0..trials)
+            .map(|_| {
+                let mut deck = self.clone();
+                deck.cards.make_contiguous().shuffle(rng);
+
+                (0..hand_size)
+                    .filter_map(|_| deck.deal_top_card())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Picks `n` distinct [Card]s uniformly at random, leaving the [Deck] unchanged.
+    ///
+    /// Returns every [Card] left, in random order, if fewer than `n` remain.
+    pub fn random_hand(&self, n: usize, rng: &mut impl Rng) -> Vec<C> {
+        index::sample(rng, self.cards.len(), n.min(self.cards.len()))
+            .into_iter()
+            .map(|position| self.cards[position])
+            .collect()
+    }
+
+    /// Lazily yields every combination of `k` [Card]s left in the [Deck].
+    ///
+    /// Each combination keeps the [Deck]'s top to bottom order. Combinations are
+    /// generated one at a time, so even large counts such as every five [Card]
+    /// hand never need to be held in memory at once.
+    pub fn combinations(&self, k: usize) -> impl Iterator<Item = Vec<C>> {
+        Combinations::new(self.cards.iter().copied().collect(), k)
+    }
+
+    /// Draws `n` [Card]s uniformly at random with replacement, so the same [Card] may repeat.
+    ///
+    /// The [Deck] is not modified. An empty [Deck] yields no [Card]s.
+    pub fn sample_with_replacement(&self, n: usize, rng: &mut impl Rng) -> Vec<C> {
+        if self.cards.is_empty() {
+            return Vec::new();
+        }
+
+        (0..n)
+            .map(|_| self.cards[rng.gen_range(0, self.cards.len())])
+            .collect()
+    }
+
+    /// Returns whether any [Card] appears more than once in the [Deck].
+    ///
+    /// Some decks, such as Pinochle, hold duplicates by design, so this is
+    /// informational rather than an error.
+    pub fn has_duplicates(&self) -> bool {
+        !self.duplicate_cards().is_empty()
+    }
+
+    /// Get each [Card] that appears more than once in the [Deck], listed once
+    /// in the order its second copy is found from the top.
+    pub fn duplicate_cards(&self) -> Vec<C> {
+        let mut duplicates = Vec::new();
+
+        for (i, card) in self.cards.iter().enumerate() {
+            if !duplicates.contains(card) && self.cards.range(..i).any(|seen| seen == card) {
+                duplicates.push(*card);
+            }
+        }
+
+        duplicates
+    }
+
+    /// Get the number of [Card]s the [Deck] can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.cards.capacity()
+    }
+
+    /// Releases spare memory left behind by dealt [Card]s.
+    pub fn shrink_to_fit(&mut self) {
+        self.cards.shrink_to_fit();
+    }
+
+    /// Returns whether the [Deck] was shuffled while it was built, rather than left in build order.
+    pub fn was_shuffled(&self) -> bool {
+        self.shuffled
+    }
+
+    /// Get the [DeckOp]s applied to the [Deck] so far, oldest first.
+    ///
+    /// Empty unless the [Deck] was built `with_history`.
+    pub fn history(&self) -> &[DeckOp] {
+        self.history.as_deref().unwrap_or(&[])
+    }
+
+    /// Describes how the [Deck] was built, such as "Full French deck, 52 cards, shuffled 7 times".
+    ///
+    /// The [Card] count is the number left in the [Deck]. [Deck]s not built from
+    /// a [DeckType] are described as custom, and several copies of one, as from
+    /// `Deck::shoe` or `duplicate_each`, as a shoe such as "2-deck Full French shoe".
+    pub fn build_summary(&self) -> String {
+        let name = match self.deck_type {
+            Some(DeckType::FullFrench) => "Full French",
+            Some(DeckType::Euchre) => "Euchre",
+            Some(DeckType::Pinochle) => "Pinochle",
+            Some(DeckType::Spider { .. }) => "Spider",
+            None => "Custom",
+        };
+        let kind = if self.deck_type.is_some() && self.deck_copies != 1 {
+            format!("{}-deck {} shoe", self.deck_copies, name)
+        } else {
+            format!("{} deck", name)
+        };
+        let shuffled = match self.shuffle_count {
+            0 => "unshuffled".to_string(),
+            1 => "shuffled 1 time".to_string(),
+            count => format!("shuffled {} times", count),
+        };
+
+        format!("{}, {} cards, {}", kind, self.cards.len(), shuffled)
+    }
+
+    /// Get the all [Card]s left in the [Deck].
+    pub fn total_cards(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Get the number of [Card]s left in the [Deck], an alias of `total_cards`.
+    pub fn remaining(&self) -> usize {
+        self.total_cards()
+    }
+
+    /// Get the number of [Card]s that have been dealt out of the [Deck] since it was finished.
+    ///
+    /// Adding [Card]s back, as with `refill_from`, or splitting the [Deck] does not
+    /// change the count. A cyclic [Deck], whose dealt [Card]s return, only counts
+    /// the [Card]s taken out with `remove_card`.
+    pub fn dealt(&self) -> usize {
+        self.dealt
+    }
+
+    /// Get each position from the top where this [Deck] and `other` hold different
+    /// [Card]s, along with this [Deck]'s [Card] and then `other`'s.
+    ///
+    /// Only [Deck]s of the same length can be compared; otherwise `None` is returned.
+    pub fn diff(&self, other: &Deck<Finished, C>) -> Option<Vec<(usize, C, C)>> {
+        if self.cards.len() != other.cards.len() {
+            return None;
+        }
+
+        let differences = self
+            .cards
+            .iter()
+            .zip(&other.cards)
+            .enumerate()
+            .filter(|(_, (mine, theirs))| mine != theirs)
+            .map(|(i, (mine, theirs))| (i, *mine, *theirs))
+            .collect();
+
+        Some(differences)
+    }
+
+    /// Copy the remaining [Card]s from top to bottom, leaving the [Deck] untouched.
+    pub fn snapshot(&self) -> Vec<C> {
+        self.cards.iter().copied().collect()
+    }
+
+    /// Consumes the [Deck], returning the remaining [Card]s from top to bottom.
+    pub fn into_vec(self) -> Vec<C> {
+        self.cards.into()
+    }
+
+    /// Deals the [Card] at `position` from the top, recording `op` as how it was dealt.
+    ///
+    /// Every way of dealing goes through here, so the history misses no [Card] and
+    /// the cyclic and cut card rules apply alike. A cyclic [Deck] returns a copy of
+    /// a bottom [Card] to the top and of any other to the bottom; otherwise the
+    /// [Card] is taken out as with `take_out`.
+    fn take_at(&mut self, position: usize, op: DeckOp) -> Option<C> {
+        if !self.cyclic {
+            return self.take_out(position, op);
+        }
+
+        let card = self.cards.remove(position)?;
+        self.record(op);
+        match op {
+            DeckOp::Deal(DeckEnd::Bottom) => self.cards.push_front(card),
+            _ => self.cards.push_back(card),
+        }
+
+        Some(card)
+    }
+
+    /// Takes the [Card] at `position` from the top out for good, even from a cyclic [Deck].
+    ///
+    /// Taking a [Card] from beneath the cut card moves the cut card down with it.
+    fn take_out(&mut self, position: usize, op: DeckOp) -> Option<C> {
+        let beneath = self.cards.len().checked_sub(position + 1)?;
+        let card = self.cards.remove(position)?;
+        self.record(op);
+
+        self.dealt += 1;
+        if let Some(below) = self.cut_card.as_mut() {
+            if beneath < *below {
+                *below -= 1;
+            }
+        }
+
+        Some(card)
+    }
+
+    /// Moves the cut card, if any, up above `added` [Card]s just put beneath it.
+    fn add_beneath_cut_card(&mut self, added: usize) {
+        if let Some(below) = self.cut_card.as_mut() {
+            *below += added;
+        }
+    }
+
+    /// Deals every [Card] from the top in turn, as `take_at` does one at a time.
+    ///
+    /// A cyclic [Deck] recycles each [Card] to the bottom, so it ends as it began.
+    fn take_all(&mut self) -> VecDeque<C> {
+        (0..self.cards.len())
+            .filter_map(|_| self.take_at(0, DeckOp::Deal(DeckEnd::Top)))
+            .collect()
+    }
+}
+
+impl Deck<Finished> {
+    /// Writes the [Deck] as space separated [Card] short codes, top [Card] first.
+    pub fn to_compact_string(&self) -> String {
+        self.cards
+            .iter()
+            .map(Card::short_code)
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Get the numerical [Rank] of `card`, with Aces high or low as configured with `aces_high`.
+    pub fn rank_value(&self, card: &Card) -> i32 {
+        card.rank().get_numerical_rank(self.aces_high)
+    }
+
+    /// Iterate over the Unicode glyphs of the [Card]s left in the [Deck], from top to bottom.
+    pub fn glyphs(&self) -> impl Iterator<Item = char> + '_ {
+        self.cards.iter().map(Card::unicode_glyph)
+    }
+
+    /// Returns whether the [Deck] holds exactly the 52 standard [Card]s, each once.
+    pub fn is_standard_french(&self) -> bool {
+        // With exactly 52 Cards, finding every standard Card rules out duplicates.
+        self.cards.len() == Rank::VALUES.len() * Suit::VALUES.len()
+            && Suit::VALUES.iter().all(|suit| {
+                Rank::VALUES
+                    .iter()
+                    .all(|rank| self.cards.contains(&Card::new(*rank, *suit)))
+            })
+    }
+
+    /// Encodes which standard [Card]s are in the [Deck] as one bit each, losing their order.
+    ///
+    /// Bit `i`, counting from the least significant, is set if the [Card] at
+    /// position `i` of [STANDARD_ORDER] is present. So bit 0 is the Ace of Hearts
+    /// and bit 51 the Two of Spades; the top 12 bits are always clear.
+    pub fn to_bitset(&self) -> u64 {
+        STANDARD_ORDER
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| self.cards.contains(card))
+            .fold(0, |bits, (position, _)| bits | 1 << position)
+    }
+
+    /// Get each standard [Card] that is not in the [Deck], in [STANDARD_ORDER].
+    pub fn missing_from_standard(&self) -> Vec<Card> {
+        STANDARD_ORDER
+            .into_iter()
+            .filter(|card| !self.cards.contains(card))
+            .collect()
+    }
+
+    /// Counts the [Card]s left of each [Rank], including [Rank]s with none left.
+    pub fn count_by_rank(&self) -> HashMap<Rank, usize> {
+        Rank::VALUES
+            .into_iter()
+            .map(|rank| {
+                (
+                    rank,
+                    self.cards.iter().filter(|card| card.rank() == rank).count(),
+                )
+            })
+            .collect()
+    }
+
+    /// Counts the [Card]s left of `suit`.
+    pub fn count_suit(&self, suit: Suit) -> usize {
+        self.cards.iter().filter(|card| card.suit() == suit).count()
+    }
+
+    /// Get the Hi-Lo running count for Blackjack card counting, worked out from the [Card]s left.
+    ///
+    /// Hi-Lo counts Two through Six as +1, Seven through Nine as 0, and Ten through
+    /// Ace as -1 as they are seen. Every full [Deck] balances to 0, so the count of
+    /// the [Card]s already dealt from full [Deck]s is minus the count of those left.
+    /// A positive count means the [Card]s left are rich in Tens and Aces.
+    pub fn hi_lo_count(&self) -> i32 {
+        -self
+            .cards
+            .iter()
+            .map(|card| match card.rank().get_numerical_rank(true) {
+                2..=6 => 1,
+                7..=9 => 0,
+                _ => -1,
+            })
+            .sum::<i32>()
+    }
+
+    /// Get the Hi-Lo true count: the running count per full [Deck] of [Card]s left.
+    ///
+    /// Returns 0 for an empty [Deck].
+    pub fn true_count(&self) -> f64 {
+        if self.cards.is_empty() {
+            return 0.0;
+        }
+
+        let decks_left = self.cards.len() as f64 / STANDARD_ORDER.len() as f64;
+        self.hi_lo_count() as f64 / decks_left
+    }
+
+    /// Counts the red and black [Card]s left, returned as `(red, black)`.
+    pub fn count_by_color(&self) -> (usize, usize) {
+        let red = self
+            .cards
+            .iter()
+            .filter(|card| card.suit().color() == Color::Red)
+            .count();
+
+        (red, self.cards.len() - red)
+    }
+
+    /// Get the highest [Card] left by numeric [Rank], or `None` if the [Deck] is empty.
+    ///
+    /// Ties between equal [Rank]s are broken as by [OrderedCard].
+    pub fn highest_card(&self, aces_high: bool) -> Option<&Card> {
+        self.cards
+            .iter()
+            .max_by_key(|card| OrderedCard::new(card, aces_high))
+    }
+
+    /// Get the lowest [Card] left by numeric [Rank], or `None` if the [Deck] is empty.
+    ///
+    /// Ties between equal [Rank]s are broken as by [OrderedCard].
+    pub fn lowest_card(&self, aces_high: bool) -> Option<&Card> {
+        self.cards
+            .iter()
+            .min_by_key(|card| OrderedCard::new(card, aces_high))
+    }
+
+    /// Deals the whole [Deck] into one pile per [Suit], such as solitaire foundations.
+    ///
+    /// Every [Suit] has a pile, even if empty, and each pile keeps the order its
+    /// [Card]s were dealt in.
+    pub fn deal_by_suit(&mut self) -> HashMap<Suit, Vec<Card>> {
+        let mut piles: HashMap<Suit, Vec<Card>> = Suit::VALUES
+            .into_iter()
+            .map(|suit| (suit, Vec::new()))
+            .collect();
+        for card in self.take_all() {
+            piles.entry(card.suit()).or_default().push(card);
+        }
+
+        piles
+    }
+
+    /// Deals `cards_each` [Card]s to each of `players` as with `deal_around`, making
+    /// sure no [Card] is dealt twice.
+    ///
+    /// Returns [DealError::NotEnoughCards] if the [Deck] runs short, or
+    /// [DealError::DuplicateCard] if two hands, or one hand, would share a [Card].
+    /// The [Deck] is left untouched on error.
+    pub fn deal_unique_hands(
+        &mut self,
+        players: usize,
+        cards_each: usize,
+    ) -> Result<Vec<Vec<Card>>, DealError> {
+        let requested = players * cards_each;
+        if !self.can_deal(requested) {
+            return Err(DealError::NotEnoughCards {
+                requested,
+                remaining: self.total_cards(),
+            });
+        }
+
+        let mut deck = self.clone();
+        let hands = deck.deal_around(players, DealDirection::Clockwise, 0, cards_each);
+
+        let mut seen = HashSet::with_capacity(requested);
+        if let Some(card) = hands.iter().flatten().find(|card| !seen.insert(**card)) {
+            return Err(DealError::DuplicateCard(*card));
+        }
+
+        *self = deck;
+        Ok(hands)
+    }
+
+    /// Deals the first [Card] from the top whose [Rank] is one of `ranks`.
+    ///
+    /// Other [Card]s stay in place. Returns `None` if no [Card] of those [Rank]s is left.
+    pub fn deal_any_rank(&mut self, ranks: &[Rank]) -> Option<Card> {
+        self.deal_first_matching(|card| ranks.contains(&card.rank()))
+    }
+
+    /// Deals [Card]s from the top until none of `suit` are left, returning every [Card] dealt.
+    ///
+    /// Deals nothing if no [Card]s of `suit` are left.
+    pub fn deal_until_suit_gone(&mut self, suit: Suit) -> Vec<Card> {
+        match self.cards.iter().rposition(|card| card.suit() == suit) {
+            Some(last) => (0..=last).filter_map(|_| self.deal_top_card()).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Deals the first [Card] from the top matching `rank` and `suit`, as in Crazy Eights.
+    ///
+    /// A `None` constraint matches any [Card]. Non-matching [Card]s stay in place.
+    pub fn deal_matching(&mut self, rank: Option<Rank>, suit: Option<Suit>) -> Option<Card> {
+        self.deal_first_matching(|card| {
+            rank.is_none_or(|rank| card.rank() == rank)
+                && suit.is_none_or(|suit| card.suit() == suit)
+        })
+    }
+
+    /// Deals up to `n` [Card]s with `deal`, grouping those that share a [Rank], as in Go Fish.
+    ///
+    /// Returns the unmatched [Card]s, and each [Rank] dealt two or more times with
+    /// its [Card]s. Both keep the order the [Card]s were dealt in.
+    pub fn deal_and_match(&mut self, n: usize) -> (Vec<Card>, HashMap<Rank, Vec<Card>>) {
+        let mut by_rank: HashMap<Rank, Vec<Card>> = HashMap::new();
+        let dealt: Vec<Card> = (0..n).filter_map(|_| self.deal()).collect();
+        for card in &dealt {
+            by_rank.entry(card.rank()).or_default().push(*card);
+        }
+
+        let unmatched = dealt
+            .into_iter()
+            .filter(|card| by_rank[&card.rank()].len() < 2)
+            .collect();
+        by_rank.retain(|_, cards| cards.len() >= 2);
+
+        (unmatched, by_rank)
+    }
+
+    /// Deals up to `n` [Card]s with `deal`, grouped by their Aces high numeric [Rank].
+    ///
+    /// Iterating the map visits the [Rank]s from lowest to highest, and each group
+    /// keeps the order its [Card]s were dealt in.
+    pub fn deal_into_rank_map(&mut self, n: usize) -> BTreeMap<i32, Vec<Card>> {
+        let mut by_rank: BTreeMap<i32, Vec<Card>> = BTreeMap::new();
+        for card in (0..n).filter_map(|_| self.deal()) {
+            by_rank
+                .entry(card.rank().get_numerical_rank(true))
+                .or_default()
+                .push(card);
+        }
+
+        by_rank
+    }
+
+    /// Deals up to `n` [Card]s with `deal`, returning the hand in the order dealt
+    /// and a copy sorted by [Rank], lowest first.
+    ///
+    /// Ties between equal [Rank]s are broken as by [OrderedCard].
+    pub fn deal_hand_with_sorted(&mut self, n: usize, aces_high: bool) -> (Vec<Card>, Vec<Card>) {
+        let hand: Vec<Card> = (0..n).filter_map(|_| self.deal()).collect();
+        let mut sorted = hand.clone();
+        sorted.sort_by(|a, b| OrderedCard::new(a, aces_high).cmp(&OrderedCard::new(b, aces_high)));
+
+        (hand, sorted)
+    }
+
+    /// Replaces the [Suit] of every [Card] with `map` of it, keeping [Rank]s and order.
+    pub fn remap_suits(&mut self, map: impl Fn(Suit) -> Suit) {
+        for card in self.cards.iter_mut() {
+            *card = card.with_suit(map(card.suit()));
+        }
+    }
+
+    /// Sorts the [Deck] by [Suit], then by [Rank] from Ace up to King.
+    ///
+    /// [Suit]s are grouped in the order of [Suit::VALUES], and the lowest [Card]
+    /// of each group is nearest the top.
+    pub fn sort_aces_low(&mut self) {
+        self.sort_by_suit_then_rank(false);
+    }
+
+    /// Sorts the [Deck] by [Suit], then by [Rank] from Two up to Ace.
+    ///
+    /// [Suit]s are grouped in the order of [Suit::VALUES], and the lowest [Card]
+    /// of each group is nearest the top.
+    pub fn sort_aces_high(&mut self) {
+        self.sort_by_suit_then_rank(true);
+    }
+
+    fn sort_by_suit_then_rank(&mut self, aces_high: bool) {
+        self.cards.make_contiguous().sort_by_key(|card| {
+            let suit = Suit::VALUES.iter().position(|suit| *suit == card.suit());
+            (suit, card.rank().get_numerical_rank(aces_high))
+        });
+    }
+
+    /// Get the probability that the next [Card] dealt is of each [Rank].
+    ///
+    /// Based on the [Card]s left in the [Deck]. An empty [Deck] gives an empty table.
+    pub fn next_card_distribution(&self) -> HashMap<Rank, f64> {
+        if self.cards.is_empty() {
+            return HashMap::new();
+        }
+
+        let total = self.cards.len() as f64;
+        self.count_by_rank()
+            .into_iter()
+            .map(|(rank, count)| (rank, count as f64 / total))
+            .collect()
+    }
+}
+
+/// Shows the [DeckState] and the [Card] order as short codes, top [Card] first.
+impl<T: DeckState> fmt::Debug for Deck<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let codes: Vec<String> = self.cards.iter().map(Card::short_code).collect();
+        write!(f, "Deck<{}> [{}]", T::NAME, codes.join(" "))
+    }
+}
+
+/// Prints every [Card] left as space separated short codes on one line, top [Card] first.
+impl fmt::Display for Deck<Finished> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_compact_string())
+    }
+}
+
+/// Iterator over every combination of `k` [Card]s drawn from a pool.
+struct Combinations<C> {
+    pool: Vec<C>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<C> Combinations<C> {
+    fn new(pool: Vec<C>, k: usize) -> Combinations<C> {
+        Combinations {
+            done: k > pool.len(),
+            indices: (0..k).collect(),
+            pool,
+        }
+    }
+}
+
+impl<C: PlayingCard> Iterator for Combinations<C> {
+    type Item = Vec<C>;
+
+    fn next(&mut self) -> Option<Vec<C>> {
+        if self.done {
+            return None;
+        }
+
+        let combination = self.indices.iter().map(|&i| self.pool[i]).collect();
+
+        // Advance the rightmost index that still has room, then reset those after it.
+        let n = self.pool.len();
+        let k = self.indices.len();
+        match (0..k).rev().find(|&i| self.indices[i] != i + n - k) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(combination)
+    }
+}
+
+/// Read access to the [Card] at a position, where index 0 is the top of the [Deck].
+///
+/// # Panics
+/// Panics if `index` is out of range, like indexing a `Vec`.
+impl<C: PlayingCard> Index<usize> for Deck<Finished, C> {
+    type Output = C;
+
+    fn index(&self, index: usize) -> &C {
+        &self.cards[index]
+    }
+}
+
+/// Builds a [Deck] from a non-empty list of [Card]s, allowing duplicates.
+///
+/// Use `Deck::try_from_cards` with `strict` set to also reject duplicates.
+impl TryFrom<Vec<Card>> for Deck<Finished> {
+    type Error = CardError;
+
+    fn try_from(cards: Vec<Card>) -> Result<Self, Self::Error> {
+        Deck::try_from_cards(cards, false)
+    }
+}
+
+/// Adds each [Card] to the bottom of the [Deck], in iteration order.
+impl<C: PlayingCard> Extend<C> for Deck<Finished, C> {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        let before = self.cards.len();
+        self.cards.extend(iter);
+        self.add_beneath_cut_card(self.cards.len() - before);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_new_returns_52_card_deck() {
+        let deck = Deck::default_new();
+
+        assert_eq!(deck.total_cards(), 52)
+    }
+
+    #[test]
+    fn custom_new_returns_52_card_deck() {
+        let deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .shuffle(7);
+
+        assert_eq!(deck.total_cards(), 52)
+    }
+
+    #[test]
+    fn deal_respects_configured_deck_end() {
+        let build = || {
+            Deck::custom_new()
+                .deal_from(DeckEnd::Bottom)
+                .deck_type(DeckType::FullFrench)
+                .no_shuffle()
+        };
+        let mut deck = build();
+        let mut reference = build();
+
+        assert_eq!(deck.deal(), reference.deal_bottom_card());
+    }
+
+    #[test]
+    fn equally_seeded_decks_shuffle_identically() {
+        let build = || {
+            Deck::custom_new_with_rng(StdRng::seed_from_u64(42))
+                .deck_type(DeckType::FullFrench)
+                .shuffle(7)
+        };
+        let mut first = build();
+        let mut second = build();
+
+        while let Some(card) = first.deal_top_card() {
+            assert_eq!(Some(card), second.deal_top_card());
+        }
+        assert_eq!(second.total_cards(), 0);
+    }
+
+    #[test]
+    fn refilling_beneath_cut_card_still_needs_reshuffle() {
+        let mut deck = Deck::default_new_seeded(4);
+        deck.insert_cut_card(12);
+        let mut discards: Vec<Card> = (0..45).filter_map(|_| deck.deal()).collect();
+        assert!(deck.needs_reshuffle());
+
+        deck.refill_from(&mut discards);
+        assert_eq!(deck.total_cards(), 52);
+        assert!(deck.needs_reshuffle());
+
+        let mut shoe = Deck::default_new();
+        shoe.insert_cut_card(12);
+        shoe.stack_on_bottom(Deck::default_new());
+
+        for _ in 0..40 {
+            assert!(!shoe.needs_reshuffle());
+            shoe.deal_top_card();
+        }
+
+        assert!(shoe.needs_reshuffle());
+    }
+
+    #[test]
+    fn dealing_past_cut_card_needs_reshuffle() {
+        let mut deck = Deck::default_new();
+        deck.insert_cut_card(12);
+
+        for _ in 0..40 {
+            assert!(!deck.needs_reshuffle());
+            deck.deal_top_card();
+        }
+
+        assert!(deck.needs_reshuffle());
+    }
+
+    #[test]
+    fn sample_hands_returns_requested_hands() {
+        let deck = Deck::default_new();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let hands = deck.sample_hands(5, 20, &mut rng);
+
+        assert_eq!(hands.len(), 20);
+        assert!(hands.iter().all(|hand| hand.len() == 5));
+        assert_eq!(deck.total_cards(), 52);
+    }
+
+    #[test]
+    fn compact_string_round_trip_preserves_order() {
+        let deck = Deck::default_new();
+        let compact = deck.to_compact_string();
+
+        let restored = Deck::from_compact_string(&compact).unwrap();
+
+        assert_eq!(restored.total_cards(), 52);
+        assert_eq!(restored.to_compact_string(), compact);
+    }
+
+    #[test]
+    fn sample_with_replacement_repeats_without_dealing() {
+        let deck = Deck::default_new();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let samples = deck.sample_with_replacement(1000, &mut rng);
+        let has_duplicate = samples
+            .iter()
+            .enumerate()
+            .any(|(i, card)| samples[..i].contains(card));
+
+        assert_eq!(samples.len(), 1000);
+        assert!(has_duplicate);
+        assert_eq!(deck.total_cards(), 52);
+    }
+
+    #[test]
+    fn top_k_matches_next_deals() {
+        let mut deck = Deck::default_new();
+        let mut reference = deck.clone();
+
+        let top = deck.top_k(3).to_vec();
+
+        assert_eq!(top.len(), 3);
+        for card in top {
+            assert_eq!(Some(card), reference.deal_top_card());
+        }
+        assert_eq!(deck.total_cards(), 52);
+    }
+
+    #[test]
+    fn every_deck_type_builds_expected_size() {
+        let expected = [
+            (DeckType::FullFrench, 52),
+            (DeckType::Euchre, 24),
+            (DeckType::Pinochle, 48),
+            (DeckType::Spider { suits: 2 }, 104),
+        ];
+
+        for (deck_type, size) in expected {
+            let deck = Deck::custom_new().deck_type(deck_type).no_shuffle();
+            assert_eq!(deck.total_cards(), size);
+        }
+    }
+
+    #[test]
+    fn was_shuffled_reports_build_choice() {
+        let unshuffled = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        assert!(Deck::default_new().was_shuffled());
+        assert!(!unshuffled.was_shuffled());
+    }
+
+    #[test]
+    fn try_deal_top_errors_when_empty() {
+        let mut deck = Deck::custom_new()
+            .custom_deck_type(&[Rank::Ace], &[Suit::Spades])
+            .unwrap()
+            .no_shuffle();
+
+        assert_eq!(deck.try_deal_top(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(deck.try_deal_top(), Err(CardError::EmptyDeck));
+    }
+
+    #[test]
+    fn eight_out_faros_restore_order() {
+        let mut deck = Deck::default_new();
+        let original = deck.to_compact_string();
+
+        for _ in 0..8 {
+            deck = deck.faro_self();
+        }
+
+        assert_eq!(deck.to_compact_string(), original);
+    }
+
+    #[test]
+    fn faro_interleaves_two_decks() {
+        let top = Deck::from_compact_string("AS KS QS").unwrap();
+        let bottom = Deck::from_compact_string("AH KH").unwrap();
+
+        assert_eq!(top.faro(bottom).to_compact_string(), "AS AH KS KH QS");
+    }
+
+    #[test]
+    fn custom_deck_type_rejects_empty_build() {
+        let deck = Deck::custom_new().custom_deck_type(&[], &Suit::VALUES);
+
+        assert_eq!(deck.err(), Some(CardError::InvalidDeck));
+    }
+
+    #[test]
+    fn zero_counts_build_documented_empty_decks() {
+        let shoe = Deck::shoe(0, DeckType::FullFrench).no_shuffle();
+        let shoe_of = Deck::custom_new().shoe_of(0, DeckType::Euchre).no_shuffle();
+        let duplicated = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .duplicate_each(0)
+            .no_shuffle();
+        let capped = Deck::custom_new()
+            .max_size(0)
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        for deck in [shoe, shoe_of, duplicated, capped] {
+            assert_eq!(deck.total_cards(), 0);
+        }
+    }
+
+    #[test]
+    fn deal_first_matching_takes_first_face_card() {
+        let mut deck = Deck::from_compact_string("5H 9C KD QS").unwrap();
+
+        let card = deck.deal_first_matching(|card| card.rank().is_face());
+
+        assert_eq!(card, Some(Card::new(Rank::King, Suit::Diamonds)));
+        assert_eq!(deck.to_compact_string(), "5H 9C QS");
+    }
+
+    #[test]
+    fn index_zero_is_top_card() {
+        let deck = Deck::default_new();
+
+        assert_eq!(&deck[0], deck.peek_top().unwrap());
+        assert_eq!(&deck[51], deck.peek_bottom().unwrap());
+    }
+
+    #[test]
+    fn cut_randomly_is_reproducible() {
+        let original = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+        let mut first = original.clone();
+        let mut second = original.clone();
+
+        first.cut_randomly(&mut StdRng::seed_from_u64(11));
+        second.cut_randomly(&mut StdRng::seed_from_u64(11));
+
+        assert_eq!(first.to_compact_string(), second.to_compact_string());
+        assert_ne!(first.to_compact_string(), original.to_compact_string());
+        assert_eq!(first.total_cards(), 52);
+        assert!(original.cards.iter().all(|card| first.cards.contains(card)));
+    }
+
+    #[test]
+    fn pinochle_deck_has_duplicates() {
+        let pinochle = Deck::custom_new()
+            .deck_type(DeckType::Pinochle)
+            .no_shuffle();
+
+        assert!(pinochle.has_duplicates());
+        assert_eq!(pinochle.duplicate_cards().len(), 24);
+        assert!(!Deck::default_new().has_duplicates());
+    }
+
+    #[test]
+    fn reverse_puts_bottom_card_on_top() {
+        let mut deck = Deck::default_new();
+        let bottom = *deck.peek_bottom().unwrap();
+
+        deck.reverse();
+
+        assert_eq!(deck.deal_top_card(), Some(bottom));
+    }
+
+    #[test]
+    fn with_jokers_adds_one_of_each_color() {
+        let red = CardKind::from(Joker::new(Color::Red));
+        let black = CardKind::from(Joker::new(Color::Black));
+
+        let mut deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .with_jokers()
+            .shuffle(7);
+
+        assert_eq!(deck.total_cards(), 54);
+        assert_eq!(deck.deal_first_matching(|card| *card == red), Some(red));
+        assert_eq!(deck.deal_first_matching(|card| *card == black), Some(black));
+        assert_eq!(deck.deal_first_matching(|card| *card == red), None);
+    }
+
+    #[test]
+    fn with_joker_count_overrides_default() {
+        let deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .with_joker_count(4)
+            .no_shuffle();
+
+        assert_eq!(deck.total_cards(), 56);
+    }
+
+    #[test]
+    fn position_of_finds_cards_in_build_order() {
+        let deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        assert_eq!(
+            deck.position_of(&Card::new(Rank::Ace, Suit::Hearts)),
+            Some(0)
+        );
+        assert_eq!(
+            deck.position_of(&Card::new(Rank::Ace, Suit::Clubs)),
+            Some(13)
+        );
+        assert_eq!(
+            deck.position_of(&Card::new(Rank::Queen, Suit::Diamonds)),
+            Some(28)
+        );
+        assert_eq!(
+            deck.peek_top().and_then(|top| deck.position_of(top)),
+            Some(0)
+        );
+
+        let mut partial = deck.clone();
+        partial.deal_top_card();
+        assert_eq!(
+            partial.position_of(&Card::new(Rank::Ace, Suit::Hearts)),
+            None
+        );
+    }
+
+    #[test]
+    fn only_suits_builds_listed_suits() {
+        let mut deck = Deck::custom_new()
+            .only_suits(&[Suit::Spades, Suit::Hearts])
+            .unwrap()
+            .shuffle(7);
+
+        assert_eq!(deck.total_cards(), 26);
+        while let Some(card) = deck.deal_top_card() {
+            assert!(matches!(card.suit(), Suit::Spades | Suit::Hearts));
+        }
+    }
+
+    #[test]
+    fn custom_card_type_builds_and_deals() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct UnoCard {
+            color: u8,
+            number: u8,
+        }
+
+        impl PlayingCard for UnoCard {}
+
+        let cards = (0..4).flat_map(|color| (0..10).map(move |number| UnoCard { color, number }));
+        let mut deck = Deck::custom_new().custom_cards(cards).no_shuffle();
+
+        assert_eq!(deck.total_cards(), 40);
+        assert_eq!(
+            deck.deal_top_card(),
+            Some(UnoCard {
+                color: 0,
+                number: 0
+            })
+        );
+        assert_eq!(
+            deck.deal_bottom_card(),
+            Some(UnoCard {
+                color: 3,
+                number: 9
+            })
+        );
+    }
+
+    #[test]
+    fn combinations_of_two_from_four_cards() {
+        let deck = Deck::from_compact_string("AS KS QS JS").unwrap();
+
+        let pairs: Vec<Vec<Card>> = deck.combinations(2).collect();
+
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(pairs[0], vec![deck[0], deck[1]]);
+        assert_eq!(pairs[5], vec![deck[2], deck[3]]);
+        assert_eq!(deck.combinations(5).count(), 0);
+    }
+
+    #[test]
+    fn default_new_seeded_is_reproducible() {
+        let first = Deck::default_new_seeded(2024);
+        let second = Deck::default_new_seeded(2024);
+
+        assert_eq!(first.to_compact_string(), second.to_compact_string());
+        assert_ne!(
+            first.to_compact_string(),
+            Deck::default_new_seeded(2025).to_compact_string()
+        );
+    }
+
+    #[test]
+    fn cyclic_deck_recycles_dealt_cards() {
+        let mut deck = Deck::default_new();
+        deck.make_cyclic();
+
+        let first = deck.deal_top_card();
+        for _ in 1..52 {
+            deck.deal_top_card();
+        }
+
+        assert_eq!(deck.deal_top_card(), first);
+        for _ in 53..60 {
+            assert!(deck.deal_top_card().is_some());
+        }
+        assert_eq!(deck.total_cards(), 52);
+    }
+
+    #[test]
+    fn shrink_to_fit_reclaims_dealt_space() {
+        let mut deck = Deck::default_new();
+        for _ in 0..48 {
+            deck.deal_top_card();
+        }
+
+        deck.shrink_to_fit();
+
+        assert!(deck.capacity() >= deck.total_cards());
+        assert!(deck.capacity() < 52);
+    }
+
+    #[test]
+    fn split_off_top_leaves_rest() {
+        let mut deck = Deck::default_new();
+        let top_card = deck[0];
+
+        let mut stock = deck.split_off_top(13);
+
+        assert_eq!(stock.total_cards(), 13);
+        assert_eq!(deck.total_cards(), 39);
+        assert_eq!(stock.deal_top_card(), Some(top_card));
+    }
+
+    #[test]
+    fn debug_shows_state_and_card_codes() {
+        let deck = Deck::custom_new()
+            .custom_deck_type(&[Rank::Ace, Rank::King], &[Suit::Spades])
+            .unwrap();
+
+        assert_eq!(format!("{:?}", deck), "Deck<Shuffling> [AS KS]");
+        assert_eq!(format!("{:?}", deck.no_shuffle()), "Deck<Finished> [AS KS]");
+    }
+
+    #[test]
+    fn deal_weighted_favours_heavy_card() {
+        let loaded = Card::new(Rank::Ace, Suit::Spades);
+        let deck = Deck::default_new();
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let hits = (0..100)
+            .filter(|_| {
+                let mut deck = deck.clone();
+                let weight = |card: &Card| if *card == loaded { 1000.0 } else { 1.0 };
+                deck.deal_weighted(weight, &mut rng) == Some(loaded)
+            })
+            .count();
+
+        assert!(hits > 80);
+    }
+
+    #[test]
+    fn deal_weighted_never_draws_zero_weight() {
+        let mut deck = Deck::from_compact_string("AS KS").unwrap();
+        let mut rng = StdRng::seed_from_u64(5);
+        let only_kings = |card: &Card| if card.rank() == Rank::King { 1.0 } else { 0.0 };
+        let king = deck[1];
+
+        assert_eq!(deck.deal_weighted(only_kings, &mut rng), Some(king));
+        assert_eq!(deck.deal_weighted(only_kings, &mut rng), None);
+    }
+
+    #[test]
+    fn into_vec_keeps_every_card_top_first() {
+        let deck = Deck::from_compact_string("AS KH 2C").unwrap();
+        let total = deck.total_cards();
+        let top = deck[0];
+
+        let cards = deck.into_vec();
+
+        assert_eq!(cards.len(), total);
+        assert_eq!(cards[0], top);
+    }
+
+    #[test]
+    fn rank_range_builds_short_deck() {
+        let deck = Deck::custom_new()
+            .rank_range(Rank::Six, Rank::Ace, true)
+            .unwrap()
+            .no_shuffle();
+
+        assert_eq!(deck.total_cards(), 36);
+    }
+
+    #[test]
+    fn rank_range_rejects_reversed_bounds() {
+        let result = Deck::custom_new().rank_range(Rank::Six, Rank::Ace, false);
+
+        assert_eq!(result.err(), Some(CardError::InvalidDeck));
+    }
+
+    #[test]
+    fn history_records_deals_in_order() {
+        let mut deck = Deck::custom_new()
+            .with_history()
+            .deck_type(DeckType::FullFrench)
+            .shuffle(1);
+
+        deck.deal_top_card();
+        deck.deal_bottom_card();
+        deck.deal_top_card();
+
+        let deals = [
+            DeckOp::Deal(DeckEnd::Top),
+            DeckOp::Deal(DeckEnd::Bottom),
+            DeckOp::Deal(DeckEnd::Top),
+        ];
+        assert_eq!(deck.history()[0], DeckOp::Shuffle);
+        assert_eq!(&deck.history()[1..], &deals);
+    }
+
+    #[test]
+    fn history_is_empty_when_not_enabled() {
+        let mut deck = Deck::default_new();
+
+        deck.deal();
+        deck.cut(10);
+
+        assert!(deck.history().is_empty());
+    }
+
+    #[test]
+    fn deal_face_up_deals_from_top_and_keeps_rest() {
+        let mut deck = Deck::from_compact_string("AS KS QS JS").unwrap();
+
+        let board = deck.deal_face_up(3);
+
+        assert_eq!(board.len(), 3);
+        assert_eq!(board[0], Card::new(Rank::Ace, Suit::Spades));
+        assert_eq!(deck.to_compact_string(), "JS");
+    }
+
+    #[test]
+    fn peek_top_n_matches_deal_order() {
+        let deck = Deck::default_new_seeded(3);
+        let mut copy = deck.clone();
+
+        let peeked = deck.peek_top_n(4);
+        let dealt: Vec<Card> = (0..4).filter_map(|_| copy.deal_top_card()).collect();
+
+        assert_eq!(peeked, dealt);
+        assert_eq!(deck.total_cards(), 52);
+        assert_eq!(deck.peek_top_n(60).len(), 52);
+    }
+
+    #[test]
+    fn deal_top_except_skips_excluded_cards() {
+        let mut deck = Deck::from_compact_string("AS KS QS").unwrap();
+        let excluded = [deck[0], deck[1]];
+        let third = deck[2];
+
+        assert_eq!(deck.deal_top_except(&excluded), Some(third));
+        assert_eq!(deck.deal_top_except(&excluded), None);
+        assert_eq!(deck.to_compact_string(), "AS KS");
+    }
+
+    #[test]
+    fn dealt_counts_cards_since_finishing() {
+        let mut deck = Deck::default_new();
+
+        for _ in 0..5 {
+            deck.deal();
+        }
+
+        assert_eq!(deck.remaining(), 47);
+        assert_eq!(deck.dealt(), 5);
+    }
+
+    #[test]
+    fn card_slice_matches_deal_order() {
+        let mut deck = Deck::default_new_seeded(8);
+        deck.deal_bottom_card();
+        let mut copy = deck.clone();
+
+        let slice = deck.as_card_slice().to_vec();
+        let dealt: Vec<Card> = std::iter::from_fn(|| copy.deal_top_card()).collect();
+
+        assert_eq!(slice.len(), 51);
+        assert_eq!(slice, dealt);
+    }
+
+    #[test]
+    fn from_short_codes_deals_in_order() {
+        let mut deck = Deck::from_short_codes(&["AS", "KH", "QD"]).unwrap();
+
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(deck.deal(), Some(Card::new(Rank::King, Suit::Hearts)));
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Queen, Suit::Diamonds)));
+        assert!(Deck::from_short_codes(&["AS", "ZZ"]).is_err());
+    }
+
+    #[test]
+    fn enumerate_iter_starts_at_top() {
+        let deck = Deck::from_compact_string("AS KS QS").unwrap();
+
+        let positions: Vec<(usize, &Card)> = deck.enumerate_iter().collect();
+
+        assert_eq!(positions[0], (0, &deck[0]));
+        assert_eq!(positions[2], (2, &deck[2]));
+        assert_eq!(deck.iter().count(), 3);
+    }
+
+    #[test]
+    fn deal_alternating_splits_deck_evenly() {
+        let mut deck = Deck::default_new();
+        let top = deck[0];
+
+        let piles = deck.deal_alternating(2);
+
+        assert_eq!(piles.len(), 2);
+        assert!(piles.iter().all(|pile| pile.len() == 26));
+        assert_eq!(piles[0][0], top);
+        assert_eq!(deck.total_cards(), 0);
+    }
+
+    #[test]
+    fn refill_from_moves_discards_to_bottom() {
+        let mut deck = Deck::default_new();
+        let mut discards: Vec<Card> = (0..10).filter_map(|_| deck.deal()).collect();
+        let last = discards[9];
+
+        deck.refill_from(&mut discards);
+
+        assert_eq!(deck.total_cards(), 52);
+        assert_eq!(deck.peek_bottom(), Some(&last));
+        assert!(discards.is_empty());
+    }
+
+    #[test]
+    fn next_card_distribution_tracks_remaining_ranks() {
+        let mut deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        let full = deck.next_card_distribution();
+        assert!(full.values().all(|p| (p - 1.0 / 13.0).abs() < 1e-9));
+
+        deck.deal();
+        let after = deck.next_card_distribution();
+        assert!((after[&Rank::Ace] - 3.0 / 51.0).abs() < 1e-9);
+        assert!((after[&Rank::King] - 4.0 / 51.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn is_standard_french_needs_every_card_once() {
+        let mut deck = Deck::default_new();
+        assert!(deck.is_standard_french());
+
+        deck.deal();
+        assert!(!deck.is_standard_french());
+
+        deck.refill_from(&mut vec![deck[0]]);
+        assert!(!deck.is_standard_french());
+    }
+
+    #[test]
+    fn deal_exact_refuses_partial_hands() {
+        let mut deck = Deck::from_compact_string("AS KS QS").unwrap();
+
+        assert!(!deck.can_deal(4));
+        assert_eq!(deck.deal_exact(4), None);
+        assert_eq!(deck.total_cards(), 3);
+
+        assert!(deck.can_deal(3));
+        assert_eq!(deck.deal_exact(3).map(|hand| hand.len()), Some(3));
+    }
+
+    #[test]
+    fn standard_order_matches_unshuffled_pack() {
+        let pack = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+        let standard = Deck::custom_new().custom_cards(STANDARD_ORDER).no_shuffle();
+
+        assert_eq!(STANDARD_ORDER.len(), 52);
+        assert!(!standard.has_duplicates());
+        assert_eq!(pack.into_vec(), STANDARD_ORDER);
+    }
+
+    #[test]
+    fn riffle_changes_order_and_keeps_cards() {
+        let deck = Deck::custom_new_with_rng(StdRng::seed_from_u64(2))
+            .deck_type(DeckType::FullFrench)
+            .riffle(7);
+
+        assert_eq!(deck.total_cards(), 52);
+        assert!(deck.was_shuffled());
+        assert!(deck.is_standard_french());
+        assert_ne!(deck.into_vec(), STANDARD_ORDER);
+    }
+
+    #[test]
+    fn extend_adds_cards_to_bottom() {
+        let mut deck = Deck::from_compact_string("AS").unwrap();
+        let discards = Deck::from_compact_string("2C 3C 4C").unwrap();
+
+        deck.extend(discards.into_vec());
+
+        assert_eq!(deck.total_cards(), 4);
+        assert_eq!(deck.to_compact_string(), "AS 2C 3C 4C");
+    }
+
+    #[test]
+    fn rotate_moves_top_to_bottom_and_wraps() {
+        let mut deck = Deck::from_compact_string("AS KS QS").unwrap();
+
+        deck.rotate(1);
+        assert_eq!(deck.to_compact_string(), "KS QS AS");
+
+        deck.rotate(5);
+        assert_eq!(deck.to_compact_string(), "AS KS QS");
+    }
+
+    #[test]
+    fn from_card_set_builds_custom_set() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Bright(u8);
+        impl PlayingCard for Bright {}
+
+        struct Brights;
+        impl CardSet for Brights {
+            type Card = Bright;
+
+            fn cards() -> Vec<Bright> {
+                (1..=4).map(Bright).collect()
+            }
+        }
+
+        let mut deck = Deck::from_card_set::<Brights>();
+
+        assert_eq!(deck.total_cards(), 4);
+        assert_eq!(deck.deal(), Some(Bright(1)));
+    }
+
+    #[test]
+    fn missing_from_standard_lists_dealt_aces() {
+        let mut deck = Deck::default_new();
+        let aces: Vec<Card> = (0..4)
+            .filter_map(|_| deck.deal_first_matching(|card| card.rank() == Rank::Ace))
+            .collect();
+
+        let missing = deck.missing_from_standard();
+
+        assert_eq!(missing.len(), 4);
+        assert!(missing.iter().all(|card| aces.contains(card)));
+    }
+
+    #[test]
+    fn bitset_round_trips_present_cards() {
+        let mut deck = Deck::default_new();
+        assert_eq!(deck.to_bitset().count_ones(), 52);
+
+        deck.deal();
+        deck.deal();
+        let bits = deck.to_bitset();
+        let rebuilt = Deck::from_bitset(bits);
+
+        assert_eq!(bits.count_ones(), 50);
+        assert_eq!(rebuilt.total_cards(), 50);
+        assert_eq!(
+            rebuilt.missing_from_standard(),
+            deck.missing_from_standard()
+        );
+    }
+
+    #[test]
+    fn deal_top_with_count_reports_remaining() {
+        let mut deck = Deck::default_new();
+        let top = deck[0];
+
+        assert_eq!(deck.deal_top_with_count(), Some((top, 51)));
+    }
+
+    #[test]
+    fn wash_mixes_all_cards() {
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .wash(&mut rng, 500);
+
+        assert!(deck.is_standard_french());
+        assert_ne!(deck.into_vec(), STANDARD_ORDER);
+    }
+
+    #[test]
+    fn count_by_color_shifts_after_dealing_heart() {
+        let mut deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+        assert_eq!(deck.count_by_color(), (26, 26));
+
+        assert_eq!(deck.deal().map(|card| card.suit()), Some(Suit::Hearts));
+        assert_eq!(deck.count_by_color(), (25, 26));
+    }
+
+    #[test]
+    fn sort_aces_low_puts_ace_before_two() {
+        let mut deck = Deck::from_compact_string("2S KH AS AH 2H").unwrap();
+
+        deck.sort_aces_low();
+        assert_eq!(deck.to_compact_string(), "AH 2H KH AS 2S");
+
+        deck.sort_aces_high();
+        assert_eq!(deck.to_compact_string(), "2H KH AH 2S AS");
+    }
+
+    #[test]
+    fn deal_and_match_groups_pairs() {
+        let mut deck = Deck::from_compact_string("7H KS 7C 2D QS").unwrap();
+
+        let (unmatched, sets) = deck.deal_and_match(4);
+
+        assert_eq!(unmatched.len(), 2);
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[&Rank::Seven].len(), 2);
+        assert_eq!(deck.total_cards(), 1);
+    }
+
+    #[test]
+    fn fix_top_deals_fixed_cards_first() {
+        let opening = vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Hearts),
+        ];
+
+        let mut deck = Deck::custom_new_with_rng(StdRng::seed_from_u64(6))
+            .deck_type(DeckType::FullFrench)
+            .fix_top(opening.clone())
+            .shuffle(7);
+
+        let unshuffled: Vec<Card> = STANDARD_ORDER
+            .into_iter()
+            .filter(|card| !opening.contains(card))
+            .collect();
+
+        assert!(deck.is_standard_french());
+        assert_eq!(deck.deal_exact(2), Some(opening));
+        assert_ne!(deck.into_vec(), unshuffled);
+    }
+
+    #[test]
+    fn deal_to_teams_rotates_between_partnerships() {
+        let mut deck = Deck::default_new();
+        let first_four = deck.peek_top_n(4);
+
+        let teams = deck.deal_to_teams(2, 2, 13);
+
+        assert_eq!(deck.total_cards(), 0);
+        assert!(teams.iter().flatten().all(|hand| hand.len() == 13));
+        assert_eq!(teams[0][0][0], first_four[0]);
+        assert_eq!(teams[1][0][0], first_four[1]);
+        assert_eq!(teams[0][1][0], first_four[2]);
+        assert_eq!(teams[1][1][0], first_four[3]);
+    }
+
+    #[test]
+    fn front_to_back_matches_successive_peeks() {
+        let mut deck = Deck::default_new_seeded(12);
+        deck.deal_bottom_card();
+        let mut copy = deck.clone();
+
+        for card in deck.front_to_back() {
+            assert_eq!(copy.peek_top(), Some(card));
+            copy.deal_top_card();
+        }
+        assert_eq!(copy.total_cards(), 0);
+    }
+
+    #[test]
+    fn deal_ends_takes_top_and_bottom() {
+        let mut deck = Deck::from_compact_string("AS KS QS").unwrap();
+        let (top, bottom) = (deck[0], deck[2]);
+
+        assert_eq!(deck.deal_ends(), (Some(top), Some(bottom)));
+        assert_eq!(deck.to_compact_string(), "KS");
+
+        let last = deck[0];
+        assert_eq!(deck.deal_ends(), (Some(last), None));
+        assert_eq!(deck.deal_ends(), (None, None));
+    }
+
+    #[test]
+    fn random_hand_leaves_deck_intact() {
+        let deck = Deck::default_new();
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let hand = deck.random_hand(5, &mut rng);
+        let as_deck = Deck::custom_new().custom_cards(hand).no_shuffle();
+
+        assert_eq!(deck.total_cards(), 52);
+        assert_eq!(as_deck.total_cards(), 5);
+        assert!(!as_deck.has_duplicates());
+    }
+
+    #[test]
+    fn shuffle_range_only_moves_cards_in_range() {
+        let mut deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        deck.shuffle_range(0..10, &mut rng);
+        let cards = deck.into_vec();
+
+        assert_eq!(cards[10..], STANDARD_ORDER[10..]);
+        assert_ne!(cards[..10], STANDARD_ORDER[..10]);
+        assert!(cards[..10]
+            .iter()
+            .all(|card| STANDARD_ORDER[..10].contains(card)));
+    }
+
+    #[test]
+    fn build_summary_describes_deck() {
+        let custom = Deck::custom_new()
+            .only_suits(&[Suit::Hearts])
+            .unwrap()
+            .riffle(1);
+
+        assert_eq!(
+            Deck::default_new().build_summary(),
+            "Full French deck, 52 cards, shuffled 7 times"
+        );
+        assert_eq!(
+            custom.build_summary(),
+            "Custom deck, 13 cards, shuffled 1 time"
+        );
+        assert_eq!(
+            Deck::shoe(2, DeckType::FullFrench)
+                .no_shuffle()
+                .build_summary(),
+            "2-deck Full French shoe, 104 cards, unshuffled"
+        );
+        assert_eq!(
+            Deck::custom_new()
+                .deck_type(DeckType::Euchre)
+                .duplicate_each(2)
+                .no_shuffle()
+                .build_summary(),
+            "2-deck Euchre shoe, 48 cards, unshuffled"
+        );
+    }
+
+    #[test]
+    fn deal_matching_applies_rank_and_suit() {
+        let mut deck = Deck::from_compact_string("2S KD 5H KH 9C").unwrap();
+        let (first_king, first_heart) = (deck[1], deck[2]);
+
+        assert_eq!(
+            deck.deal_matching(None, Some(Suit::Hearts)),
+            Some(first_heart)
+        );
+        assert_eq!(deck.deal_matching(Some(Rank::King), None), Some(first_king));
+        assert_eq!(
+            deck.deal_matching(Some(Rank::King), Some(Suit::Clubs)),
+            None
+        );
+        assert_eq!(deck.to_compact_string(), "2S KH 9C");
+    }
+
+    #[test]
+    fn all_standard_cards_are_unique() {
+        let cards = all_standard_cards();
+        let deck = Deck::custom_new().custom_cards(cards).no_shuffle();
+
+        assert_eq!(cards.len(), 52);
+        assert!(deck.is_standard_french());
+        assert!(deck.missing_from_standard().is_empty());
+    }
+
+    #[test]
+    fn deal_by_suit_fills_four_foundations() {
+        let mut deck = Deck::default_new();
+
+        let piles = deck.deal_by_suit();
+
+        assert_eq!(piles.len(), 4);
+        assert!(piles.values().all(|pile| pile.len() == 13));
+        assert!(piles[&Suit::Clubs]
+            .iter()
+            .all(|card| card.suit() == Suit::Clubs));
+        assert_eq!(deck.total_cards(), 0);
+    }
+
+    #[test]
+    fn try_from_vec_validates_cards() {
+        let cards = Deck::from_compact_string("AS KS AS").unwrap().into_vec();
+
+        let deck = Deck::try_from(cards.clone()).unwrap();
+
+        assert_eq!(deck.to_compact_string(), "AS KS AS");
+        assert_eq!(
+            Deck::try_from(Vec::new()).err(),
+            Some(CardError::InvalidDeck)
+        );
+        assert_eq!(
+            Deck::try_from_cards(cards, true).err(),
+            Some(CardError::InvalidDeck)
+        );
+    }
+
+    #[test]
+    fn peek_range_matches_deals_and_clamps() {
+        let deck = Deck::default_new_seeded(21);
+        let mut copy = deck.clone();
+        let dealt: Vec<Card> = (0..5).filter_map(|_| copy.deal_top_card()).collect();
+
+        assert_eq!(deck.peek_range(0..5), dealt);
+        assert_eq!(deck.peek_range(50..60).len(), 2);
+        assert!(deck.peek_range(60..70).is_empty());
+    }
+
+    #[test]
+    fn highest_and_lowest_card_of_full_deck() {
+        let deck = Deck::default_new();
+
+        assert_eq!(deck.highest_card(true).map(Card::rank), Some(Rank::Ace));
+        assert_eq!(deck.lowest_card(true).map(Card::rank), Some(Rank::Two));
+        assert_eq!(deck.lowest_card(false).map(Card::rank), Some(Rank::Ace));
+        assert_eq!(Deck::from_cards(Vec::new()).highest_card(true), None);
+    }
+
+    #[test]
+    fn deal_around_follows_direction_from_start() {
+        let deck = Deck::from_compact_string("AS KS QS JS").unwrap();
+        let (mut clockwise, mut counter) = (deck.clone(), deck);
+
+        let clockwise = clockwise.deal_around(4, DealDirection::Clockwise, 2, 1);
+        let counter = counter.deal_around(4, DealDirection::CounterClockwise, 2, 1);
+
+        let codes = |hands: Vec<Vec<Card>>| -> Vec<String> {
+            hands.iter().map(|hand| hand[0].short_code()).collect()
+        };
+        assert_eq!(codes(clockwise), ["QS", "JS", "AS", "KS"]);
+        assert_eq!(codes(counter), ["QS", "KS", "AS", "JS"]);
+    }
+
+    #[test]
+    fn insert_random_is_reproducible() {
+        let returned = Card::new(Rank::Ace, Suit::Spades);
+        let mut first = Deck::from_compact_string("2C 3C 4C 5C 6C").unwrap();
+        let mut second = first.clone();
+
+        first.insert_random(returned, &mut StdRng::seed_from_u64(9));
+        second.insert_random(returned, &mut StdRng::seed_from_u64(9));
+
+        assert_eq!(first.total_cards(), 6);
+        assert_eq!(first.position_of(&returned), second.position_of(&returned));
+    }
+
+    #[test]
+    fn display_lists_cards_top_first() {
+        let deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        let shown = format!("{}", deck);
+
+        assert!(shown.starts_with("AH KH QH JH TH"));
+        assert_eq!(shown.split(' ').count(), 52);
+    }
+
+    #[test]
+    fn six_deck_shoe_holds_six_packs() {
+        let shoe = Deck::shoe(6, DeckType::FullFrench).no_shuffle();
+
+        assert_eq!(shoe.total_cards(), 312);
+        assert_eq!(shoe.count_by_rank()[&Rank::Ace], 24);
+    }
+
+    #[test]
+    fn standard_minus_leaves_out_dealt_cards() {
+        let dealt = Deck::from_compact_string("AS KD 7H 7C 2S")
+            .unwrap()
+            .into_vec();
+        let mut rng = StdRng::seed_from_u64(13);
+
+        let deck = Deck::standard_minus(&dealt, &mut rng);
+
+        assert_eq!(deck.total_cards(), 47);
+        assert!(dealt.iter().all(|card| deck.position_of(card).is_none()));
+        assert_eq!(deck.missing_from_standard().len(), 5);
+    }
+
+    #[test]
+    fn try_deals_error_once_deck_runs_dry() {
+        let mut deck = Deck::default_new();
+        while deck.try_deal_bottom().is_ok() {}
+
+        assert_eq!(deck.try_deal_top(), Err(CardError::EmptyDeck));
+        assert_eq!(deck.try_deal_bottom(), Err(CardError::EmptyDeck));
+    }
+
+    #[test]
+    fn split_halves_preserve_order() {
+        let deck = Deck::default_new();
+        let twenty_seventh = deck[26];
+
+        let (top, bottom) = deck.split(26);
+
+        assert_eq!(top.total_cards(), 26);
+        assert_eq!(bottom.total_cards(), 26);
+        assert_eq!(bottom[0], twenty_seventh);
+    }
+
+    #[test]
+    fn glyphs_follow_deck_order() {
+        let deck = Deck::from_compact_string("AS TH KC").unwrap();
+
+        let glyphs: Vec<char> = deck.glyphs().collect();
+
+        assert_eq!(glyphs, ['\u{1F0A1}', '\u{1F0BA}', '\u{1F0DE}']);
+    }
+
+    #[test]
+    fn cut_to_card_brings_target_to_top() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let mut deck = Deck::default_new();
+
+        assert!(deck.cut_to_card(ace));
+        assert_eq!(deck.peek_top(), Some(&ace));
+        assert_eq!(deck.total_cards(), 52);
+
+        deck.deal();
+        assert!(!deck.cut_to_card(ace));
+    }
+
+    #[test]
+    fn stacking_split_piles_restores_deck() {
+        let deck = Deck::default_new();
+        let order = deck.to_compact_string();
+
+        let (top, mut bottom) = deck.split(20);
+        bottom.stack_on_top(top);
+        assert_eq!(bottom.total_cards(), 52);
+        assert_eq!(bottom.to_compact_string(), order);
+
+        let (top, bottom) = bottom.split(30);
+        let mut restacked = bottom;
+        restacked.stack_on_bottom(top);
+        assert_eq!(restacked.total_cards(), 52);
+    }
+
+    #[test]
+    fn full_french_build_order_is_pinned() {
+        let mut deck = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        assert_eq!(
+            deck.to_compact_string(),
+            "AH KH QH JH TH 9H 8H 7H 6H 5H 4H 3H 2H \
+             AC KC QC JC TC 9C 8C 7C 6C 5C 4C 3C 2C \
+             AD KD QD JD TD 9D 8D 7D 6D 5D 4D 3D 2D \
+             AS KS QS JS TS 9S 8S 7S 6S 5S 4S 3S 2S"
+        );
+
+        deck.reverse();
+        assert_eq!(deck[0], Card::new(Rank::Two, Suit::Spades));
+        assert_eq!(deck[51], Card::new(Rank::Ace, Suit::Hearts));
+    }
+
+    #[test]
+    fn max_size_truncates_large_shoes() {
+        let shoe = Deck::custom_new()
+            .max_size(6 * 52)
+            .shoe_of(100, DeckType::FullFrench)
+            .no_shuffle();
+
+        assert_eq!(shoe.total_cards(), 312);
+        assert_eq!(shoe.count_by_rank()[&Rank::Ace], 24);
+    }
+
+    #[test]
+    fn rank_value_uses_deck_ace_setting() {
+        let ace = Card::new(Rank::Ace, Suit::Clubs);
+        let low = Deck::custom_new()
+            .aces_high(false)
+            .deck_type(DeckType::FullFrench)
+            .shuffle(1);
+
+        assert_eq!(low.rank_value(&ace), 1);
+        assert_eq!(Deck::default_new().rank_value(&ace), 14);
+    }
+
+    #[test]
+    fn deal_hand_with_sorted_returns_both_orders() {
+        let mut deck = Deck::from_compact_string("9H AS 2C KD 5H").unwrap();
+
+        let (hand, sorted) = deck.deal_hand_with_sorted(5, true);
+
+        assert_eq!(Deck::from_cards(hand).to_compact_string(), "9H AS 2C KD 5H");
+        assert_eq!(
+            Deck::from_cards(sorted).to_compact_string(),
+            "2C 5H 9H KD AS"
+        );
+        assert_eq!(deck.total_cards(), 0);
+    }
+
+    #[test]
+    fn remove_card_takes_it_out_of_the_deck() {
+        let mut deck = Deck::from_cards(STANDARD_ORDER.to_vec());
+        let queen = Card::new(Rank::Queen, Suit::Diamonds);
+
+        assert_eq!(deck.remove_card(&queen), Some(queen));
+        assert_eq!(deck.total_cards(), 51);
+        assert!(!deck.remaining_contains(&queen));
+        assert_eq!(deck.remove_card(&queen), None);
+        assert_eq!(deck.peek_top(), Some(&STANDARD_ORDER[0]));
+    }
+
+    #[test]
+    fn deal_until_suit_gone_stops_after_last_of_suit() {
+        let mut deck = Deck::from_compact_string("2H 5S AH KD 3C").unwrap();
+
+        let dealt = deck.deal_until_suit_gone(Suit::Hearts);
+
+        assert_eq!(Deck::from_cards(dealt).to_compact_string(), "2H 5S AH");
+        assert_eq!(deck.count_suit(Suit::Hearts), 0);
+        assert_eq!(deck.total_cards(), 2);
+        assert!(deck.deal_until_suit_gone(Suit::Hearts).is_empty());
+    }
+
+    #[test]
+    fn as_grid_lays_out_full_rows() {
+        let deck = Deck::from_cards(STANDARD_ORDER.to_vec());
+
+        let grid = deck.as_grid(13);
+
+        assert_eq!(grid.len(), 4);
+        assert!(grid.iter().all(|row| row.len() == 13));
+        assert_eq!(grid[1][0], STANDARD_ORDER[13]);
+        assert_eq!(deck.as_grid(10).last().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn partial_shuffle_moves_only_a_few_cards() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let unshuffled = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle()
+            .into_vec();
+
+        let untouched = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .partial_shuffle_with(0, &mut rng);
+        let nudged = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .partial_shuffle_with(3, &mut rng)
+            .into_vec();
+        let moved = nudged
+            .iter()
+            .zip(&unshuffled)
+            .filter(|(card, original)| card != original)
+            .count();
+
+        assert_eq!(untouched.into_vec(), unshuffled);
+        assert!(moved > 0 && moved <= 6);
+    }
+
+    #[test]
+    fn sort_by_orders_suits_alphabetically() {
+        let mut deck = Deck::from_compact_string("5H 2S KC 9D AH").unwrap();
+
+        deck.sort_by(|a, b| a.suit().to_string().cmp(&b.suit().to_string()));
+
+        assert_eq!(deck.to_compact_string(), "KC 9D 5H AH 2S");
+    }
+
+    #[test]
+    fn cloned_deck_is_independent() {
+        let mut deck = Deck::default_new_seeded(5);
+        let copy = deck.clone();
+        let before = copy.snapshot();
+
+        deck.deal_top_card();
+        deck.deal_top_card();
+
+        assert_eq!(copy.snapshot(), before);
+        assert_eq!(copy.total_cards(), 52);
+        assert_eq!(deck.snapshot(), before[2..]);
+    }
+
+    #[test]
+    fn recorded_seed_replays_the_shuffle() {
+        let (deck, seed) = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .shuffle_recording(4);
+
+        let replay = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .shuffle_seeded(4, seed);
+
+        assert_eq!(replay.into_vec(), deck.into_vec());
+    }
+
+    #[test]
+    fn gather_in_order_undoes_a_round_robin_deal() {
+        let mut deck = Deck::from_compact_string("AS 2H 3C 4D 5S").unwrap();
+        let hands = deck.deal_alternating(2);
+
+        deck.gather_in_order(&hands);
+
+        assert_eq!(deck.to_compact_string(), "AS 2H 3C 4D 5S");
+    }
+
+    #[test]
+    fn diff_reports_a_swapped_pair() {
+        let before = Deck::from_compact_string("AS 2H 3C 4D").unwrap();
+        let after = Deck::from_compact_string("AS 4D 3C 2H").unwrap();
+        let two = Card::new(Rank::Two, Suit::Hearts);
+        let four = Card::new(Rank::Four, Suit::Diamonds);
+
+        assert_eq!(
+            before.diff(&after),
+            Some(vec![(1, two, four), (3, four, two)])
+        );
+        assert_eq!(before.diff(&before), Some(Vec::new()));
+        assert_eq!(before.diff(&Deck::from_compact_string("AS").unwrap()), None);
+    }
+
+    #[test]
+    fn deal_any_rank_takes_first_face_card() {
+        let mut deck = Deck::from_compact_string("4H 9S QD 2C KH").unwrap();
+        let faces = [Rank::Jack, Rank::Queen, Rank::King];
+
+        assert_eq!(
+            deck.deal_any_rank(&faces),
+            Some(Card::new(Rank::Queen, Suit::Diamonds))
+        );
+        assert_eq!(deck.to_compact_string(), "4H 9S 2C KH");
+        assert_eq!(deck.deal_any_rank(&[Rank::Ace]), None);
+    }
+
+    #[test]
+    fn duplicate_each_doubles_a_euchre_deck() {
+        let deck = Deck::custom_new()
+            .deck_type(DeckType::Euchre)
+            .duplicate_each(2)
+            .no_shuffle();
+
+        let mut counts: HashMap<Card, usize> = HashMap::new();
+        for card in deck.iter() {
+            *counts.entry(*card).or_default() += 1;
+        }
+
+        assert_eq!(deck.total_cards(), 48);
+        assert_eq!(counts.len(), 24);
+        assert!(counts.values().all(|count| *count == 2));
+    }
+
+    #[test]
+    fn hi_lo_count_rises_as_low_cards_leave() {
+        let mut deck = Deck::from_cards(STANDARD_ORDER.to_vec());
+        assert_eq!(deck.hi_lo_count(), 0);
+        assert_eq!(deck.true_count(), 0.0);
+
+        let low = [Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six];
+        while deck.deal_any_rank(&low).is_some() {}
+
+        assert_eq!(deck.hi_lo_count(), 20);
+        assert_eq!(deck.true_count(), 20.0 / (32.0 / 52.0));
+    }
+
+    #[test]
+    fn deal_unique_hands_rejects_shared_cards() {
+        let mut single = Deck::default_new();
+        let hands = single.deal_unique_hands(4, 13).unwrap();
+        assert_eq!(hands.iter().map(Vec::len).sum::<usize>(), 52);
+
+        let mut contrived = Deck::from_compact_string("AS 2H AS 3C 4D").unwrap();
+        assert_eq!(
+            contrived.deal_unique_hands(2, 2),
+            Err(DealError::DuplicateCard(Card::new(Rank::Ace, Suit::Spades)))
+        );
+        assert_eq!(contrived.total_cards(), 5);
+        assert_eq!(
+            contrived.deal_unique_hands(3, 2),
+            Err(DealError::NotEnoughCards {
+                requested: 6,
+                remaining: 5
+            })
+        );
+    }
+
+    #[test]
+    fn deal_into_rank_map_groups_in_ascending_order() {
+        let mut deck = Deck::from_compact_string("KH 4C AS KD 9S").unwrap();
+
+        let by_rank = deck.deal_into_rank_map(5);
+
+        assert_eq!(
+            by_rank.keys().copied().collect::<Vec<i32>>(),
+            vec![4, 9, 13, 14]
+        );
+        assert_eq!(
+            by_rank[&13],
+            vec![
+                Card::new(Rank::King, Suit::Hearts),
+                Card::new(Rank::King, Suit::Diamonds)
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_into_keeps_face_cards() {
+        let deck = Deck::from_cards(STANDARD_ORDER.to_vec());
+
+        let faces = deck.filter_into(|card| card.rank().is_face());
+
+        assert_eq!(faces.total_cards(), 12);
+        assert_eq!(faces.dealt(), 0);
+        assert!(faces.to_compact_string().starts_with("KH QH JH KC"));
+    }
+
+    #[test]
+    fn remap_suits_turns_hearts_into_spades() {
+        let mut deck = Deck::from_compact_string("AH 7C QH 2D").unwrap();
+
+        deck.remap_suits(|suit| match suit {
+            Suit::Hearts => Suit::Spades,
+            other => other,
+        });
+
+        assert_eq!(deck.to_compact_string(), "AS 7C QS 2D");
+        assert_eq!(deck.count_suit(Suit::Hearts), 0);
+    }
+
+    #[test]
+    fn spider_decks_repeat_their_suits() {
+        let one_suit = Deck::custom_new()
+            .deck_type(DeckType::Spider { suits: 1 })
+            .no_shuffle();
+        assert_eq!(one_suit.total_cards(), 104);
+        assert_eq!(one_suit.count_suit(Suit::Spades), 104);
+
+        let four_suits = Deck::custom_new()
+            .deck_type(DeckType::Spider { suits: 4 })
+            .no_shuffle();
+        let double = Deck::shoe(2, DeckType::FullFrench).no_shuffle();
+        assert_eq!(four_suits.into_vec(), double.into_vec());
+
+        assert_eq!(
+            Deck::custom_new()
+                .try_deck_type(DeckType::Spider { suits: 3 })
+                .err(),
+            Some(CardError::InvalidDeck)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Spider is played with 1, 2 or 4 suits")]
+    fn spider_with_unsupported_suits_panics() {
+        Deck::custom_new().deck_type(DeckType::Spider { suits: 3 });
+    }
+
+    #[test]
+    fn biased_shuffle_keeps_order_as_bias_rises() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let built = || Deck::custom_new().deck_type(DeckType::FullFrench);
+        let moved = |deck: Deck<Finished>| {
+            deck.into_vec()
+                .iter()
+                .zip(&STANDARD_ORDER)
+                .filter(|(card, original)| card != original)
+                .count()
+        };
+
+        let lazy = built().biased_shuffle(1.0, &mut rng);
+        assert!(!lazy.was_shuffled());
+        assert_eq!(moved(lazy), 0);
+        assert!(moved(built().biased_shuffle(0.0, &mut rng)) > 40);
+    }
+
+    #[test]
+    fn history_records_positional_deals_and_splits() {
+        let mut deck = Deck::custom_new()
+            .with_history()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+
+        deck.deal_first_matching(|card| card.suit() == Suit::Clubs);
+        deck.rotate(60);
+        deck.split_off_top(4);
+
+        assert_eq!(
+            deck.history(),
+            &[DeckOp::DealAt(13), DeckOp::Cut(9), DeckOp::SplitOff(4)]
+        );
+    }
+
+    #[test]
+    fn positional_deals_follow_cyclic_and_cut_card_rules() {
+        let two = Card::new(Rank::Two, Suit::Hearts);
+        let mut cyclic = Deck::from_compact_string("AS 2H 3C 4D").unwrap();
+        cyclic.make_cyclic();
+        assert_eq!(cyclic.remove_card(&two), Some(two));
+        assert!(!cyclic.remaining_contains(&two));
+        assert_eq!(cyclic.to_compact_string(), "AS 3C 4D");
+
+        let mut shoe = Deck::from_compact_string("AS 2H 3C 4D 5S").unwrap();
+        shoe.insert_cut_card(2);
+        shoe.remove_card(&Card::new(Rank::Four, Suit::Diamonds));
+        shoe.deal_top_card();
+        shoe.deal_top_card();
+        assert!(!shoe.needs_reshuffle());
+        shoe.deal_top_card();
+        assert!(shoe.needs_reshuffle());
+    }
+
+    #[test]
+    fn dealing_a_cyclic_deck_whole_recycles_every_card() {
+        let mut deck = Deck::default_new_seeded(3);
+        deck.make_cyclic();
+        let before = deck.snapshot();
+
+        let piles = deck.deal_alternating(2);
+        assert_eq!(piles.concat().len(), 52);
+        assert_eq!(deck.total_cards(), 52);
+        assert_eq!(deck.dealt(), 0);
+        assert_eq!(deck.snapshot(), before);
+    }
+
+    #[test]
+    fn dealt_ignores_cards_added_back_or_split_off() {
+        let mut deck = Deck::default_new();
+        let mut discards: Vec<Card> = (0..5).filter_map(|_| deck.deal()).collect();
+
+        deck.refill_from(&mut discards);
+        assert_eq!(deck.total_cards(), 52);
+        assert_eq!(deck.dealt(), 5);
+
+        let (top, rest) = deck.split(10);
+        assert_eq!(top.dealt(), 0);
+        assert_eq!(rest.dealt(), 5);
     }
 }
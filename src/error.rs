@@ -0,0 +1,86 @@
+//! A single error type covering every fallible operation in the crate.
+//!
+//! Fallible [Deck](crate::deck::Deck) operations return [CardError] directly.
+//! The more specific errors, such as [ParseCardError] from parsing a single
+//! [Card](crate::card::Card), convert into it with `?`, so callers that handle
+//! errors from several operations can match on [CardError] alone.
+
+use crate::card::ParseCardError;
+use crate::wire::WireError;
+use std::error::Error;
+use std::fmt;
+
+/// Errors from any fallible operation on [Card](crate::card::Card)s and [Deck](crate::deck::Deck)s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardError {
+    /// The [Deck](crate::deck::Deck) has no [Card](crate::card::Card)s left.
+    EmptyDeck,
+    /// A [Card](crate::card::Card) code was not exactly two characters long.
+    ParseCard(String),
+    /// A character is not a [Rank](crate::rank::Rank) code.
+    InvalidRank(char),
+    /// A character is not a [Suit](crate::suit::Suit) code.
+    InvalidSuit(char),
+    /// The [Deck](crate::deck::Deck) was configured in a way that cannot be built.
+    InvalidDeck,
+    /// An encoded [Deck](crate::deck::Deck) was truncated or corrupted in transit.
+    CorruptWire,
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardError::EmptyDeck => write!(f, "the deck has no cards left"),
+            CardError::ParseCard(code) => write!(f, "card code '{}' must be two characters", code),
+            CardError::InvalidRank(rank) => write!(f, "'{}' is not a valid rank", rank),
+            CardError::InvalidSuit(suit) => write!(f, "'{}' is not a valid suit", suit),
+            CardError::InvalidDeck => write!(f, "the deck cannot be built as configured"),
+            CardError::CorruptWire => write!(f, "the encoded deck is corrupt"),
+        }
+    }
+}
+
+impl Error for CardError {}
+
+impl From<ParseCardError> for CardError {
+    fn from(error: ParseCardError) -> CardError {
+        match error {
+            ParseCardError::InvalidLength(code) => CardError::ParseCard(code),
+            ParseCardError::InvalidRank(rank) => CardError::InvalidRank(rank),
+            ParseCardError::InvalidSuit(suit) => CardError::InvalidSuit(suit),
+        }
+    }
+}
+
+impl From<WireError> for CardError {
+    fn from(_: WireError) -> CardError {
+        CardError::CorruptWire
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Card;
+    use crate::deck::Deck;
+
+    fn deal_parsed(codes: &str) -> Result<Card, CardError> {
+        let mut deck = Deck::from_compact_string(codes)?;
+        deck.deal();
+        deck.try_deal_top()
+    }
+
+    #[test]
+    fn fallible_operations_return_card_error() {
+        assert_eq!(deal_parsed("AS XS"), Err(CardError::InvalidRank('X')));
+        assert_eq!(deal_parsed("AS"), Err(CardError::EmptyDeck));
+        assert_eq!(
+            deal_parsed("AS KS").map(|card| card.short_code()),
+            Ok("KS".to_string())
+        );
+        assert_eq!(
+            "1C".parse::<Card>().map_err(CardError::from),
+            Err(CardError::InvalidRank('1'))
+        );
+    }
+}
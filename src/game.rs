@@ -0,0 +1,151 @@
+//! Presets for common card games.
+//!
+//! Each [Game] knows which [DeckType] it is played with, so a ready to deal
+//! [Deck] can be built without knowing the game's deck composition.
+
+use crate::card::Card;
+use crate::deck::{DealError, Deck, DeckType, Finished};
+
+/// Number of times a [Game]'s [Deck] is shuffled, matching `Deck::default_new()`.
+const SHUFFLES: usize = 7;
+
+/// Card games with a known [Deck] composition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    Poker,
+    Blackjack,
+    Euchre,
+    War,
+}
+
+impl Game {
+    /// Get the [DeckType] the [Game] is played with.
+    pub fn deck_type(&self) -> DeckType {
+        match self {
+            Game::Poker | Game::Blackjack | Game::War => DeckType::FullFrench,
+            Game::Euchre => DeckType::Euchre,
+        }
+    }
+
+    /// Get the largest number of [Card]s a player may hold in one hand of the [Game].
+    ///
+    /// Blackjack allows 11, the most [Card]s that can be drawn without going bust,
+    /// and War deals half of the [Deck] to each of two players.
+    pub fn max_hand_size(&self) -> usize {
+        match self {
+            Game::Poker | Game::Euchre => 5,
+            Game::Blackjack => 11,
+            Game::War => 26,
+        }
+    }
+
+    /// Build a shuffled [Deck] ready to play the [Game].
+    pub fn new_deck(&self) -> Deck<Finished> {
+        Deck::custom_new()
+            .deck_type(self.deck_type())
+            .shuffle(SHUFFLES)
+    }
+}
+
+impl Deck<Finished> {
+    /// Deals a hand of `n` [Card]s from the top, checked against the rules of `game`.
+    ///
+    /// Returns [DealError::HandTooLarge] if `game` does not allow `n` [Card]s in a
+    /// hand, or [DealError::NotEnoughCards] if the [Deck] runs short. The [Deck] is
+    /// left untouched on error.
+    pub fn deal_validated_hand(&mut self, n: usize, game: Game) -> Result<Vec<Card>, DealError> {
+        let allowed = game.max_hand_size();
+        if n > allowed {
+            return Err(DealError::HandTooLarge {
+                requested: n,
+                allowed,
+            });
+        }
+
+        let remaining = self.total_cards();
+        if n > remaining {
+            return Err(DealError::NotEnoughCards {
+                requested: n,
+                remaining,
+            });
+        }
+
+        Ok((0..n).filter_map(|_| self.deal_top_card()).collect())
+    }
+}
+
+/// Decides a dealer by having each player cut a [Card] from their own [Deck].
+///
+/// Returns the index of the [Deck] that turned up the highest [Rank](crate::rank::Rank).
+/// Players who tie for highest cut again until one wins. Returns `None` if there
+/// are no [Deck]s, or a tied player's [Deck] runs out before the tie is broken.
+pub fn cut_for_high(decks: &mut [Deck<Finished>], aces_high: bool) -> Option<usize> {
+    let mut contenders: Vec<usize> = (0..decks.len()).collect();
+
+    while contenders.len() > 1 {
+        let cuts = contenders
+            .iter()
+            .map(|&player| {
+                let card = decks[player].deal()?;
+                Some((player, card.rank().get_numerical_rank(aces_high)))
+            })
+            .collect::<Option<Vec<(usize, i32)>>>()?;
+
+        let best = cuts.iter().map(|(_, rank)| *rank).max()?;
+        contenders = cuts
+            .into_iter()
+            .filter(|(_, rank)| *rank == best)
+            .map(|(player, _)| player)
+            .collect();
+    }
+
+    contenders.first().copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_decks_have_expected_sizes() {
+        assert_eq!(Game::Euchre.new_deck().total_cards(), 24);
+        assert_eq!(Game::Poker.new_deck().total_cards(), 52);
+    }
+
+    #[test]
+    fn validated_hand_respects_poker_limit() {
+        let mut deck = Game::Poker.new_deck();
+
+        assert_eq!(
+            deck.deal_validated_hand(6, Game::Poker),
+            Err(DealError::HandTooLarge {
+                requested: 6,
+                allowed: 5
+            })
+        );
+        assert_eq!(deck.total_cards(), 52);
+        assert_eq!(deck.deal_validated_hand(5, Game::Poker).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn cut_for_high_picks_the_ace() {
+        let mut decks = [
+            Deck::from_compact_string("KS 2H").unwrap(),
+            Deck::from_compact_string("AD 3C").unwrap(),
+            Deck::from_compact_string("QH 4S").unwrap(),
+        ];
+
+        assert_eq!(cut_for_high(&mut decks, true), Some(1));
+    }
+
+    #[test]
+    fn cut_for_high_recuts_ties() {
+        let mut decks = [
+            Deck::from_compact_string("KS 2H").unwrap(),
+            Deck::from_compact_string("KD 9C").unwrap(),
+        ];
+
+        assert_eq!(cut_for_high(&mut decks, true), Some(1));
+        assert_eq!(cut_for_high(&mut [], true), None);
+    }
+}
@@ -0,0 +1,243 @@
+//! Analysis of a hand of [Card]s.
+//!
+//! These helpers work on any slice of [Card]s, whether dealt into a hand or
+//! laid out on the table.
+
+use crate::card::{Card, OrderedCard};
+use crate::rank::Rank;
+use crate::suit::Suit;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// A hand of [Card]s held by a player.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Hand {
+    cards: Vec<Card>,
+}
+
+impl Hand {
+    /// Creates a new Hand holding the given [Card]s.
+    pub fn new(cards: Vec<Card>) -> Hand {
+        Hand { cards }
+    }
+
+    /// Get the [Card]s in the Hand, in the order they were received.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Add a [Card] to the Hand.
+    pub fn push(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+
+    /// Get the number of [Card]s in the Hand.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether the Hand holds no [Card]s.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Counts the [Card]s of each [Suit] in the Hand, including Suits with none.
+    pub fn suit_counts(&self) -> HashMap<Suit, usize> {
+        Suit::VALUES
+            .into_iter()
+            .map(|suit| {
+                (
+                    suit,
+                    self.cards.iter().filter(|card| card.suit() == suit).count(),
+                )
+            })
+            .collect()
+    }
+
+    /// Counts the [Card]s of each [Rank] in the Hand, including Ranks with none.
+    pub fn rank_counts(&self) -> HashMap<Rank, usize> {
+        Rank::VALUES
+            .into_iter()
+            .map(|rank| {
+                (
+                    rank,
+                    self.cards.iter().filter(|card| card.rank() == rank).count(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<Card>> for Hand {
+    fn from(cards: Vec<Card>) -> Hand {
+        Hand::new(cards)
+    }
+}
+
+/// Counts each distinct [Rank] among the [Card]s.
+///
+/// Sorted by count, most common first, with ties broken by the higher [Rank]
+/// (Aces high). A full house therefore lists its three of a kind before its pair.
+pub fn rank_multiplicities(cards: &[Card]) -> Vec<(Rank, usize)> {
+    let mut counts: Vec<(Rank, usize)> = Rank::VALUES
+        .into_iter()
+        .map(|rank| {
+            (
+                rank,
+                cards.iter().filter(|card| card.rank() == rank).count(),
+            )
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect();
+
+    // Rank::VALUES runs from Ace down, so a stable sort keeps higher Ranks first on ties.
+    counts.sort_by_key(|(_, count)| Reverse(*count));
+
+    counts
+}
+
+/// Sums the numeric [Rank] of every [Card].
+pub fn rank_sum(cards: &[Card], aces_high: bool) -> i32 {
+    cards
+        .iter()
+        .map(|card| card.rank().get_numerical_rank(aces_high))
+        .sum()
+}
+
+/// Counts the [Card]s as in cribbage, where face cards are worth 10 and Aces 1.
+pub fn cribbage_count(cards: &[Card]) -> i32 {
+    cards
+        .iter()
+        .map(|card| card.rank().get_numerical_rank(false).min(10))
+        .sum()
+}
+
+/// Get the highest [Card] by numeric [Rank], or `None` for no [Card]s.
+///
+/// Ties between equal [Rank]s go to the [Suit] listed last in [Suit::VALUES],
+/// as ordered by [OrderedCard].
+pub fn highest(cards: &[Card], aces_high: bool) -> Option<&Card> {
+    cards
+        .iter()
+        .max_by_key(|card| OrderedCard::new(card, aces_high))
+}
+
+/// Get the lowest [Card] by numeric [Rank], or `None` for no [Card]s.
+///
+/// Ties between equal [Rank]s go to the [Suit] listed first in [Suit::VALUES],
+/// as ordered by [OrderedCard].
+pub fn lowest(cards: &[Card], aces_high: bool) -> Option<&Card> {
+    cards
+        .iter()
+        .min_by_key(|card| OrderedCard::new(card, aces_high))
+}
+
+/// Get the highest [Card] of each [Suit] among the [Card]s, for breaking flush ties.
+///
+/// [Suit]s without any [Card]s are left out.
+pub fn suit_high_cards(cards: &[Card], aces_high: bool) -> HashMap<Suit, Card> {
+    let mut high: HashMap<Suit, Card> = HashMap::new();
+    for card in cards {
+        high.entry(card.suit())
+            .and_modify(|best| {
+                if OrderedCard::new(card, aces_high) > OrderedCard::new(best, aces_high) {
+                    *best = *card;
+                }
+            })
+            .or_insert(*card);
+    }
+
+    high
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_multiplicities_lists_full_house_trips_first() {
+        let hand = [
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+
+        let counts = rank_multiplicities(&hand);
+
+        assert_eq!(counts, vec![(Rank::Four, 3), (Rank::King, 2)]);
+    }
+
+    #[test]
+    fn suit_counts_of_flush_hand() {
+        let hand = Hand::new(
+            [Rank::Two, Rank::Six, Rank::Nine, Rank::Jack, Rank::Ace]
+                .into_iter()
+                .map(|rank| Card::new(rank, Suit::Clubs))
+                .collect(),
+        );
+
+        let counts = hand.suit_counts();
+
+        assert_eq!(counts[&Suit::Clubs], 5);
+        assert_eq!(counts[&Suit::Hearts], 0);
+        assert_eq!(counts[&Suit::Diamonds], 0);
+        assert_eq!(counts[&Suit::Spades], 0);
+        assert_eq!(hand.rank_counts()[&Rank::Ace], 1);
+    }
+
+    #[test]
+    fn rank_sum_and_cribbage_count_of_hand() {
+        let hand = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Jack, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ];
+
+        assert_eq!(rank_sum(&hand, true), 14 + 13 + 11 + 5);
+        assert_eq!(rank_sum(&hand, false), 1 + 13 + 11 + 5);
+        assert_eq!(cribbage_count(&hand), 1 + 10 + 10 + 5);
+    }
+
+    #[test]
+    fn highest_and_lowest_depend_on_ace_value() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let king = Card::new(Rank::King, Suit::Hearts);
+        let two = Card::new(Rank::Two, Suit::Clubs);
+        let hand = [
+            Card::new(Rank::Seven, Suit::Hearts),
+            king,
+            ace,
+            two,
+            Card::new(Rank::Nine, Suit::Diamonds),
+        ];
+
+        assert_eq!(highest(&hand, true), Some(&ace));
+        assert_eq!(lowest(&hand, true), Some(&two));
+        assert_eq!(highest(&hand, false), Some(&king));
+        assert_eq!(lowest(&hand, false), Some(&ace));
+        assert_eq!(highest(&[], true), None);
+    }
+
+    #[test]
+    fn suit_high_cards_finds_best_of_each_suit() {
+        let hand = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Clubs),
+        ];
+
+        let high = suit_high_cards(&hand, true);
+
+        assert_eq!(high.len(), 2);
+        assert_eq!(high[&Suit::Hearts], Card::new(Rank::Ace, Suit::Hearts));
+        assert_eq!(high[&Suit::Clubs], Card::new(Rank::Four, Suit::Clubs));
+        assert_eq!(
+            suit_high_cards(&hand, false)[&Suit::Hearts],
+            Card::new(Rank::Queen, Suit::Hearts)
+        );
+    }
+}
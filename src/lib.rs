@@ -1,4 +1,16 @@
+pub mod blackjack;
 pub mod card;
+pub mod dealer;
 pub mod deck;
+pub mod error;
+pub mod game;
+pub mod hand;
+pub mod pile;
+pub mod poker;
 pub mod rank;
+pub mod shuffle;
+pub mod stats;
 pub mod suit;
+pub mod texas_holdem;
+pub mod trick;
+pub mod wire;
@@ -0,0 +1,70 @@
+//! Face-down piles of [Card]s, such as the tableau and foundations in solitaire.
+//!
+//! Unlike a [Hand](crate::hand::Hand), a [Pile] is a stack: [Card]s are added
+//! and taken from the top only.
+
+use crate::card::Card;
+use crate::deck::{Deck, Finished};
+
+/// An ordered stack of [Card]s, where the last [Card] pushed is on top.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Pile {
+    cards: Vec<Card>,
+}
+
+impl Pile {
+    /// Creates a new, empty Pile.
+    pub fn new() -> Pile {
+        Pile { cards: Vec::new() }
+    }
+
+    /// Place a [Card] on top of the Pile.
+    pub fn push(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+
+    /// Take the top [Card] off the Pile.
+    pub fn pop(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Look at the top [Card] of the Pile without taking it.
+    pub fn peek(&self) -> Option<&Card> {
+        self.cards.last()
+    }
+
+    /// Get the number of [Card]s in the Pile.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether the Pile holds no [Card]s.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Turns the Pile into a [Deck], keeping the top [Card] of the Pile on top.
+    pub fn into_deck(self) -> Deck<Finished> {
+        Deck::custom_new()
+            .custom_cards(self.cards.into_iter().rev())
+            .no_shuffle()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_deck_keeps_top_card_on_top() {
+        let mut pile = Pile::new();
+        for code in ["2C", "7D", "KH"] {
+            pile.push(code.parse().unwrap());
+        }
+        assert_eq!(pile.peek().map(Card::short_code), Some("KH".to_string()));
+
+        let deck = pile.into_deck();
+
+        assert_eq!(deck.to_compact_string(), "KH 7D 2C");
+    }
+}
@@ -0,0 +1,276 @@
+//! Poker hand evaluation.
+//!
+//! A hand is scored as a [HandValue]: its [PokerHand] category followed by the
+//! [Rank]s used to break ties between hands of the same category. Hands with
+//! more than five [Card]s are scored by their best five [Card]s.
+
+use crate::card::Card;
+use crate::deck::{DealDirection, Deck, Finished};
+use crate::hand::rank_multiplicities;
+use crate::rank::Rank;
+use crate::suit::Suit;
+use std::cmp::{Ordering, Reverse};
+
+/// Number of [Card]s that make up a poker hand.
+const HAND_SIZE: usize = 5;
+
+/// Categories of poker hands, from weakest to strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PokerHand {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+/// The strength of an evaluated poker hand.
+///
+/// Values compare first by [PokerHand] category, then by the tie-breaking
+/// [Rank]s, so the stronger hand is always the greater value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandValue {
+    category: PokerHand,
+    tiebreak: Vec<i32>,
+}
+
+impl HandValue {
+    /// Get the [PokerHand] category of the hand.
+    pub fn category(&self) -> PokerHand {
+        self.category
+    }
+
+    /// Get the Aces high numeric [Rank]s used to break ties, most significant first.
+    pub fn tiebreak(&self) -> &[i32] {
+        &self.tiebreak
+    }
+}
+
+/// Evaluates a hand of [Card]s, using the best five if more are given.
+pub fn evaluate(cards: &[Card]) -> HandValue {
+    if cards.len() <= HAND_SIZE {
+        return evaluate_five(cards);
+    }
+
+    // An empty hand is the weakest possible value, so any real hand replaces it.
+    let mut best = evaluate_five(&[]);
+    for_each_combination(cards, HAND_SIZE, &mut |hand| {
+        let value = evaluate_five(hand);
+        if value > best {
+            best = value;
+        }
+    });
+
+    best
+}
+
+/// Compares two hands, returning `Greater` when `a` wins and `Equal` on a tie.
+pub fn compare_hands(a: &[Card], b: &[Card]) -> Ordering {
+    evaluate(a).cmp(&evaluate(b))
+}
+
+impl Deck<Finished> {
+    /// Deals five [Card]s to each of `players` one at a time, and scores every hand.
+    ///
+    /// Hands are returned with their [PokerHand] category, strongest first as
+    /// ranked by [evaluate]. Hands that tie keep their seat order, and hands come
+    /// up short if the [Deck] runs out.
+    pub fn deal_and_showdown(&mut self, players: usize) -> Vec<(Vec<Card>, PokerHand)> {
+        let mut scored: Vec<(Vec<Card>, HandValue)> = self
+            .deal_around(players, DealDirection::Clockwise, 0, HAND_SIZE)
+            .into_iter()
+            .map(|hand| {
+                let value = evaluate(&hand);
+                (hand, value)
+            })
+            .collect();
+        scored.sort_by_key(|(_, value)| Reverse(value.clone()));
+
+        scored
+            .into_iter()
+            .map(|(hand, value)| (hand, value.category()))
+            .collect()
+    }
+
+    /// Returns whether five [Card]s of `suit` in consecutive [Rank]s are still in the [Deck].
+    ///
+    /// The Ace counts both high and low, so the wheel (Ace to Five) qualifies.
+    pub fn straight_flush_possible(&self, suit: Suit) -> bool {
+        // Indexed by numeric rank, from the low Ace at 1 to the high Ace at 14.
+        let mut present = [false; 15];
+        for card in self.iter().filter(|card| card.suit() == suit) {
+            present[card.rank().get_numerical_rank(true) as usize] = true;
+            present[card.rank().get_numerical_rank(false) as usize] = true;
+        }
+
+        present[1..]
+            .windows(HAND_SIZE)
+            .any(|run| run.iter().all(|held| *held))
+    }
+}
+
+/// Evaluates a hand of at most five [Card]s.
+fn evaluate_five(cards: &[Card]) -> HandValue {
+    let multiplicities = rank_multiplicities(cards);
+    let counts: Vec<usize> = multiplicities.iter().map(|(_, count)| *count).collect();
+    let mut tiebreak: Vec<i32> = multiplicities
+        .iter()
+        .map(|(rank, _)| rank.get_numerical_rank(true))
+        .collect();
+
+    let is_flush =
+        cards.len() == HAND_SIZE && cards.iter().all(|card| card.suit() == cards[0].suit());
+    let straight_high = match counts.len() == HAND_SIZE {
+        true => straight_high(&tiebreak),
+        false => None,
+    };
+
+    if let Some(high) = straight_high {
+        tiebreak = vec![high];
+    }
+
+    let category = match (counts.as_slice(), straight_high.is_some(), is_flush) {
+        (_, true, true) => PokerHand::StraightFlush,
+        ([4, ..], _, _) => PokerHand::FourOfAKind,
+        ([3, 2], _, _) => PokerHand::FullHouse,
+        (_, _, true) => PokerHand::Flush,
+        (_, true, _) => PokerHand::Straight,
+        ([3, ..], _, _) => PokerHand::ThreeOfAKind,
+        ([2, 2, ..], _, _) => PokerHand::TwoPair,
+        ([2, ..], _, _) => PokerHand::OnePair,
+        _ => PokerHand::HighCard,
+    };
+
+    HandValue { category, tiebreak }
+}
+
+/// Returns the high card of a straight from five distinct descending values.
+///
+/// The wheel (Ace to Five) plays the Ace low, so its high card is the Five.
+fn straight_high(values: &[i32]) -> Option<i32> {
+    let ace = Rank::Ace.get_numerical_rank(true);
+
+    if values.windows(2).all(|pair| pair[0] - pair[1] == 1) {
+        Some(values[0])
+    } else if values == [ace, 5, 4, 3, 2] {
+        Some(5)
+    } else {
+        None
+    }
+}
+
+/// Calls `f` with every combination of `k` [Card]s, preserving their relative order.
+fn for_each_combination(cards: &[Card], k: usize, f: &mut impl FnMut(&[Card])) {
+    fn recurse(cards: &[Card], k: usize, chosen: &mut Vec<Card>, f: &mut impl FnMut(&[Card])) {
+        if chosen.len() == k {
+            f(chosen);
+            return;
+        }
+
+        for (i, card) in cards.iter().enumerate() {
+            if cards.len() - i < k - chosen.len() {
+                break;
+            }
+            chosen.push(*card);
+            recurse(&cards[i + 1..], k, chosen, f);
+            chosen.pop();
+        }
+    }
+
+    recurse(cards, k, &mut Vec::with_capacity(k), f);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::STANDARD_ORDER;
+
+    fn hand(cards: [(Rank, Suit); 5]) -> Vec<Card> {
+        cards
+            .into_iter()
+            .map(|(rank, suit)| Card::new(rank, suit))
+            .collect()
+    }
+
+    #[test]
+    fn pair_of_kings_beats_pair_of_queens() {
+        let kings = hand([
+            (Rank::King, Suit::Hearts),
+            (Rank::King, Suit::Clubs),
+            (Rank::Seven, Suit::Spades),
+            (Rank::Five, Suit::Diamonds),
+            (Rank::Two, Suit::Hearts),
+        ]);
+        let queens = hand([
+            (Rank::Queen, Suit::Hearts),
+            (Rank::Queen, Suit::Clubs),
+            (Rank::Ace, Suit::Spades),
+            (Rank::Nine, Suit::Diamonds),
+            (Rank::Eight, Suit::Hearts),
+        ]);
+
+        assert_eq!(evaluate(&kings).category(), PokerHand::OnePair);
+        assert_eq!(compare_hands(&kings, &queens), Ordering::Greater);
+    }
+
+    #[test]
+    fn identical_hands_tie() {
+        let a = hand([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Jack, Suit::Clubs),
+            (Rank::Nine, Suit::Spades),
+            (Rank::Six, Suit::Diamonds),
+            (Rank::Three, Suit::Hearts),
+        ]);
+        let b = hand([
+            (Rank::Ace, Suit::Spades),
+            (Rank::Jack, Suit::Diamonds),
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Six, Suit::Clubs),
+            (Rank::Three, Suit::Spades),
+        ]);
+
+        assert_eq!(compare_hands(&a, &b), Ordering::Equal);
+    }
+
+    #[test]
+    fn straight_flush_needs_five_in_a_row_of_one_suit() {
+        let full = Deck::from_cards(STANDARD_ORDER.to_vec());
+        for suit in Suit::VALUES {
+            assert!(full.straight_flush_possible(suit));
+        }
+
+        let mut deck = full.clone();
+        for rank in [Rank::Five, Rank::Ten] {
+            deck.remove_card(&Card::new(rank, Suit::Clubs));
+        }
+
+        assert!(!deck.straight_flush_possible(Suit::Clubs));
+        assert!(deck.straight_flush_possible(Suit::Hearts));
+    }
+
+    #[test]
+    fn showdown_lists_strongest_hand_first() {
+        // Dealt one at a time, seat 0 gets the pair, seat 1 the flush and seat 2 trips.
+        let mut deck =
+            Deck::from_compact_string("2H 2S 7C 2D 4S 7D 9C 6S 7H KH 8S 3C QD JS 5D").unwrap();
+
+        let results = deck.deal_and_showdown(3);
+        let categories: Vec<PokerHand> = results.iter().map(|(_, category)| *category).collect();
+
+        assert_eq!(
+            categories,
+            [
+                PokerHand::Flush,
+                PokerHand::ThreeOfAKind,
+                PokerHand::OnePair
+            ]
+        );
+        assert!(results.iter().all(|(hand, _)| hand.len() == 5));
+        assert_eq!(deck.total_cards(), 0);
+    }
+}
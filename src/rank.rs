@@ -38,6 +38,17 @@ impl Rank {
         Self::Two,
     ];
 
+    /// Get the Rank at position `index` of [Rank::VALUES], from 0 for Ace to 12 for Two.
+    pub fn from_index(index: usize) -> Option<Rank> {
+        Rank::VALUES.get(index).copied()
+    }
+
+    /// Get the position of the Rank in [Rank::VALUES].
+    pub fn index(&self) -> usize {
+        // Ranks are declared in the same order as VALUES.
+        *self as usize
+    }
+
     /// Get the numerical representation of the Rank.
     pub fn get_numerical_rank(&self, aces_high: bool) -> i32 {
         match aces_high {
@@ -46,6 +57,92 @@ impl Rank {
         }
     }
 
+    /// Get the numerical representation of the Rank as a `u8`, from 1 to 14.
+    pub fn value_u8(&self, aces_high: bool) -> u8 {
+        // Every mapping lies between 1 and 14, so the conversion never truncates.
+        self.get_numerical_rank(aces_high) as u8
+    }
+
+    /// Get the absolute difference between the numerical values of two Ranks.
+    pub fn distance(&self, other: &Rank, aces_high: bool) -> i32 {
+        (self.get_numerical_rank(aces_high) - other.get_numerical_rank(aces_high)).abs()
+    }
+
+    /// Returns whether the Rank is a face card: Jack, Queen or King.
+    pub fn is_face(&self) -> bool {
+        matches!(self, Rank::Jack | Rank::Queen | Rank::King)
+    }
+
+    /// Returns whether the Rank is an Ace.
+    pub fn is_ace(&self) -> bool {
+        matches!(self, Rank::Ace)
+    }
+
+    /// Returns whether the Rank is a number card, from Two to Ten.
+    pub fn is_number(&self) -> bool {
+        !self.is_face() && !self.is_ace()
+    }
+
+    /// Get the number of pips printed on a number card.
+    ///
+    /// Returns `None` for face cards and Aces, which carry a picture or a single
+    /// large symbol instead.
+    pub fn pips(&self) -> Option<u8> {
+        match self.is_number() {
+            true => Some(self._aces_high_mapping() as u8),
+            false => None,
+        }
+    }
+
+    /// Get the single character code of the Rank, using `T` for Ten.
+    pub fn short_code(&self) -> char {
+        match self {
+            Rank::Ace => 'A',
+            Rank::King => 'K',
+            Rank::Queen => 'Q',
+            Rank::Jack => 'J',
+            Rank::Ten => 'T',
+            Rank::Nine => '9',
+            Rank::Eight => '8',
+            Rank::Seven => '7',
+            Rank::Six => '6',
+            Rank::Five => '5',
+            Rank::Four => '4',
+            Rank::Three => '3',
+            Rank::Two => '2',
+        }
+    }
+
+    /// Get the Rank matching a single character code, ignoring case.
+    pub fn from_short_code(code: char) -> Option<Rank> {
+        Rank::VALUES
+            .into_iter()
+            .find(|rank| rank.short_code() == code.to_ascii_uppercase())
+    }
+
+    /// Get the display name of every Rank, in the order of [Rank::VALUES].
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        Rank::VALUES.into_iter().map(|rank| rank.name())
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Rank::Ace => "Ace",
+            Rank::King => "King",
+            Rank::Queen => "Queen",
+            Rank::Jack => "Jack",
+            Rank::Ten => "10",
+            Rank::Nine => "9",
+            Rank::Eight => "8",
+            Rank::Seven => "7",
+            Rank::Six => "6",
+            Rank::Five => "5",
+            Rank::Four => "4",
+            Rank::Three => "3",
+            Rank::Two => "2",
+        }
+    }
+
     /// Returns the numerical representation of an Aces High deck.
     fn _aces_high_mapping(&self) -> i32 {
         match &self {
@@ -88,20 +185,67 @@ impl Rank {
 /// Returns a user friendly string representation of the Rank
 impl fmt::Display for Rank {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Rank::Ace => write!(f, "Ace"),
-            Rank::King => write!(f, "King"),
-            Rank::Queen => write!(f, "Queen"),
-            Rank::Jack => write!(f, "Jack"),
-            Rank::Ten => write!(f, "10"),
-            Rank::Nine => write!(f, "9"),
-            Rank::Eight => write!(f, "8"),
-            Rank::Seven => write!(f, "7"),
-            Rank::Six => write!(f, "6"),
-            Rank::Five => write!(f, "5"),
-            Rank::Four => write!(f, "4"),
-            Rank::Three => write!(f, "3"),
-            Rank::Two => write!(f, "2"),
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_classify_as_face_ace_or_number() {
+        for rank in Rank::VALUES {
+            let expected = match rank {
+                Rank::Jack | Rank::Queen | Rank::King => (true, false, false),
+                Rank::Ace => (false, true, false),
+                _ => (false, false, true),
+            };
+
+            assert_eq!((rank.is_face(), rank.is_ace(), rank.is_number()), expected);
+        }
+    }
+
+    #[test]
+    fn pips_only_exist_on_number_cards() {
+        assert_eq!(Rank::Five.pips(), Some(5));
+        assert_eq!(Rank::Ten.pips(), Some(10));
+        assert_eq!(Rank::King.pips(), None);
+        assert_eq!(Rank::Ace.pips(), None);
+    }
+
+    #[test]
+    fn distance_depends_on_ace_value() {
+        assert_eq!(Rank::Ten.distance(&Rank::King, true), 3);
+        assert_eq!(Rank::King.distance(&Rank::Ten, true), 3);
+        assert_eq!(Rank::Ace.distance(&Rank::Two, true), 12);
+        assert_eq!(Rank::Ace.distance(&Rank::Two, false), 1);
+    }
+
+    #[test]
+    fn index_round_trips_every_rank() {
+        for rank in Rank::VALUES {
+            assert_eq!(Rank::from_index(rank.index()), Some(rank));
+        }
+        assert_eq!(Rank::from_index(13), None);
+    }
+
+    #[test]
+    fn names_match_display() {
+        let names: Vec<&str> = Rank::names().collect();
+
+        assert_eq!(names.len(), 13);
+        assert_eq!(names[0], "Ace");
+        assert_eq!(names[4], format!("{}", Rank::Ten));
+    }
+
+    #[test]
+    fn value_u8_stays_in_range() {
+        for rank in Rank::VALUES {
+            assert!((2..=14).contains(&rank.value_u8(true)));
+            assert!((1..=13).contains(&rank.value_u8(false)));
         }
+        assert_eq!(Rank::Ace.value_u8(true), 14);
+        assert_eq!(Rank::Ace.value_u8(false), 1);
     }
 }
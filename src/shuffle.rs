@@ -0,0 +1,191 @@
+//! Shuffling techniques modelled on how dealers handle a [Deck] at the table.
+//!
+//! A [ShuffleSequence] chains several [ShuffleOp]s, such as a riffle, then a
+//! cut, then another riffle, and applies them in order when executed.
+//!
+//! # Examples
+//! ```
+//! use playing_cards::deck::{Deck, DeckType};
+//! use playing_cards::shuffle::ShuffleOp;
+//!
+//! let deck = Deck::custom_new()
+//!     .deck_type(DeckType::FullFrench)
+//!     .shuffle_sequence()
+//!     .push(ShuffleOp::Riffle)
+//!     .push(ShuffleOp::Cut(26))
+//!     .push(ShuffleOp::Riffle)
+//!     .execute();
+//! assert_eq!(deck.total_cards(), 52);
+//! ```
+
+use crate::card::{Card, PlayingCard};
+use crate::deck::{Deck, Finished, Shuffling};
+use rand::{Rng, RngCore};
+use std::collections::VecDeque;
+
+/// Largest packet the dealer pulls off the top during an overhand shuffle.
+const MAX_OVERHAND_PACKET: usize = 8;
+
+/// A single shuffling technique applied to a [Deck].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShuffleOp {
+    /// Split the [Deck] roughly in half and interleave the halves.
+    Riffle,
+    /// Pull small packets off the top and pile them in reverse order.
+    Overhand,
+    /// Move the given number of cards from the top to the bottom.
+    Cut(usize),
+}
+
+/// A sequence of [ShuffleOp]s to apply to a [Deck] in order.
+pub struct ShuffleSequence<C: PlayingCard = Card> {
+    deck: Deck<Shuffling, C>,
+    ops: Vec<ShuffleOp>,
+}
+
+impl<C: PlayingCard> ShuffleSequence<C> {
+    /// Begin a sequence of [ShuffleOp]s for the [Deck].
+    pub fn new(deck: Deck<Shuffling, C>) -> ShuffleSequence<C> {
+        ShuffleSequence {
+            deck,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Add a [ShuffleOp] to the end of the sequence.
+    pub fn push(mut self, op: ShuffleOp) -> ShuffleSequence<C> {
+        self.ops.push(op);
+        self
+    }
+
+    /// Apply every [ShuffleOp] in the order they were pushed.
+    pub fn execute(self) -> Deck<Finished, C> {
+        self.deck.apply_shuffles(&self.ops)
+    }
+}
+
+/// Counts the perfect faro shuffles that return a [Deck] of `deck_size` cards to its order.
+///
+/// An out-faro keeps the top card on top, as `Deck::faro_self` does, and an
+/// in-faro moves it second. With an odd size the half dealt from first holds the
+/// extra card. Eight out-faros restore a 52 card [Deck], while in-faros take 52.
+pub fn faro_period(deck_size: usize, out_shuffle: bool) -> usize {
+    let halfway = match out_shuffle {
+        true => deck_size.div_ceil(2),
+        false => deck_size / 2,
+    };
+    let original: VecDeque<usize> = (0..deck_size).collect();
+    let mut cards = original.clone();
+    let mut period = 0;
+
+    loop {
+        let bottom = cards.split_off(halfway);
+        cards = match out_shuffle {
+            true => interleave(cards, bottom),
+            false => interleave(bottom, cards),
+        };
+        period += 1;
+
+        if cards == original {
+            return period;
+        }
+    }
+}
+
+/// Riffles the cards following the Gilbert-Shannon-Reeds model.
+///
+/// The cut point is binomially distributed around the middle, and each card
+/// drops from a half with probability proportional to that half's size.
+pub(crate) fn riffle<C>(cards: &mut VecDeque<C>, rng: &mut dyn RngCore) {
+    let cut = (0..cards.len()).filter(|_| rng.gen::<bool>()).count();
+    let mut right = cards.split_off(cut);
+    let mut left = std::mem::replace(cards, VecDeque::with_capacity(cut + right.len()));
+
+    while !left.is_empty() || !right.is_empty() {
+        let remaining = left.len() + right.len();
+        let card = match rng.gen_range(0, remaining) < left.len() {
+            true => left.pop_front(),
+            false => right.pop_front(),
+        };
+        cards.extend(card);
+    }
+}
+
+/// Overhand shuffles the cards, moving packets from the top onto a new pile.
+pub(crate) fn overhand<C>(cards: &mut VecDeque<C>, rng: &mut dyn RngCore) {
+    let mut pile = VecDeque::with_capacity(cards.len());
+
+    while !cards.is_empty() {
+        let packet = rng.gen_range(1, MAX_OVERHAND_PACKET + 1).min(cards.len());
+        let rest = cards.split_off(packet);
+
+        for card in cards.drain(..).rev() {
+            pile.push_front(card);
+        }
+
+        *cards = rest;
+    }
+
+    *cards = pile;
+}
+
+/// Cuts the cards, moving `at` of them from the top to the bottom.
+pub(crate) fn cut<C>(cards: &mut VecDeque<C>, at: usize) {
+    let at = at.min(cards.len());
+    cards.rotate_left(at);
+}
+
+/// Perfectly interleaves two piles one card at a time, starting with `first`.
+///
+/// Once either pile runs out, the rest of the other follows in order.
+pub(crate) fn interleave<C>(first: VecDeque<C>, second: VecDeque<C>) -> VecDeque<C> {
+    let mut cards = VecDeque::with_capacity(first.len() + second.len());
+    let mut first = first.into_iter();
+    let mut second = second.into_iter();
+
+    loop {
+        match (first.next(), second.next()) {
+            (None, None) => break,
+            (a, b) => cards.extend(a.into_iter().chain(b)),
+        }
+    }
+
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::DeckType;
+
+    #[test]
+    fn riffle_cut_riffle_preserves_cards() {
+        let mut original = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .no_shuffle();
+        let mut shuffled = Deck::custom_new()
+            .deck_type(DeckType::FullFrench)
+            .shuffle_sequence()
+            .push(ShuffleOp::Riffle)
+            .push(ShuffleOp::Cut(26))
+            .push(ShuffleOp::Riffle)
+            .execute();
+
+        let mut dealt = Vec::new();
+        while let Some(card) = shuffled.deal_top_card() {
+            dealt.push(card);
+        }
+
+        assert_eq!(dealt.len(), 52);
+        while let Some(card) = original.deal_top_card() {
+            assert!(dealt.contains(&card));
+        }
+    }
+
+    #[test]
+    fn faro_period_of_a_standard_deck() {
+        assert_eq!(faro_period(52, true), 8);
+        assert_eq!(faro_period(52, false), 52);
+        assert_eq!(faro_period(1, true), 1);
+    }
+}
@@ -0,0 +1,174 @@
+//! Statistics for measuring the quality of shuffles and deals.
+//!
+//! These work on slices of [Card]s, so they can compare a [Deck]
+//! before and after shuffling, or the hands dealt from it.
+
+use crate::card::Card;
+use crate::deck::{Deck, Finished};
+use crate::hand::rank_sum;
+
+/// Estimates how disordered `cards` are relative to a `reference` order, from 0 to 1.
+///
+/// Counts the pairs of [Card]s that appear in the opposite order to `reference`,
+/// divided by the most such pairs possible. An identical order gives 0 and a
+/// reversed order gives 1. [Card]s missing from `reference` are ignored, and
+/// fewer than two comparable [Card]s give 0.
+pub fn permutation_entropy(cards: &[Card], reference: &[Card]) -> f64 {
+    let positions: Vec<usize> = cards
+        .iter()
+        .filter_map(|card| reference.iter().position(|other| other == card))
+        .collect();
+
+    let n = positions.len();
+    if n < 2 {
+        return 0.0;
+    }
+
+    let max_inversions = n * (n - 1) / 2;
+    inversions(&positions) as f64 / max_inversions as f64
+}
+
+/// Counts the pairs of [Card]s whose [Rank](crate::rank::Rank)s are out of ascending order.
+///
+/// A hand sorted from lowest to highest has 0 inversions, and one sorted from
+/// highest to lowest has the most possible. [Card]s of equal rank never count
+/// as out of order.
+pub fn count_inversions(cards: &[Card], aces_high: bool) -> usize {
+    let ranks: Vec<i32> = cards
+        .iter()
+        .map(|card| card.rank().get_numerical_rank(aces_high))
+        .collect();
+
+    inversions(&ranks)
+}
+
+/// Get the probability that the next two [Card]s dealt from `deck` share a [Rank](crate::rank::Rank).
+///
+/// Based on the [Card]s left in the [Deck]. Returns 0 if fewer than two remain.
+pub fn pair_probability(deck: &Deck<Finished>) -> f64 {
+    let total = deck.total_cards();
+    if total < 2 {
+        return 0.0;
+    }
+
+    let pairs: usize = deck
+        .count_by_rank()
+        .values()
+        .map(|count| count * count.saturating_sub(1))
+        .sum();
+    pairs as f64 / (total * (total - 1)) as f64
+}
+
+/// Measures how evenly a deal split the [Rank](crate::rank::Rank)s between `hands`.
+///
+/// Returns the variance of each hand's [rank_sum], so hands of equal total give 0
+/// and lopsided deals give larger values. No hands give 0.
+pub fn deal_fairness(hands: &[Vec<Card>], aces_high: bool) -> f64 {
+    if hands.is_empty() {
+        return 0.0;
+    }
+
+    let totals: Vec<f64> = hands
+        .iter()
+        .map(|hand| rank_sum(hand, aces_high) as f64)
+        .collect();
+    let mean = totals.iter().sum::<f64>() / totals.len() as f64;
+
+    totals
+        .iter()
+        .map(|total| (total - mean).powi(2))
+        .sum::<f64>()
+        / totals.len() as f64
+}
+
+/// Returns whether `hands` hold exactly `deck_size` [Card]s between them.
+///
+/// Catches deals meant to use the whole [Deck], as in War, that leave [Card]s
+/// behind or deal too many.
+pub fn is_full_deal(hands: &[Vec<Card>], deck_size: usize) -> bool {
+    hands.iter().map(Vec::len).sum::<usize>() == deck_size
+}
+
+/// Counts the pairs of values that are out of ascending order.
+fn inversions<T: PartialOrd>(values: &[T]) -> usize {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            values[i + 1..]
+                .iter()
+                .filter(|later| *later < value)
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::STANDARD_ORDER;
+    use crate::rank::Rank;
+
+    #[test]
+    fn entropy_ranges_from_identical_to_reversed() {
+        let mut reversed = STANDARD_ORDER;
+        reversed.reverse();
+
+        assert_eq!(permutation_entropy(&STANDARD_ORDER, &STANDARD_ORDER), 0.0);
+        assert_eq!(permutation_entropy(&reversed, &STANDARD_ORDER), 1.0);
+    }
+
+    #[test]
+    fn shuffled_deck_has_middling_entropy() {
+        let shuffled = Deck::default_new_seeded(1).into_vec();
+
+        let entropy = permutation_entropy(&shuffled, &STANDARD_ORDER);
+
+        assert!(entropy > 0.3 && entropy < 0.7);
+    }
+
+    #[test]
+    fn inversions_of_sorted_reversed_and_partial_hands() {
+        let cards = |codes: &str| Deck::from_compact_string(codes).unwrap().into_vec();
+
+        assert_eq!(count_inversions(&cards("2S 5H 9C KD AS"), true), 0);
+        assert_eq!(count_inversions(&cards("AS KD 9C 5H 2S"), true), 10);
+        assert_eq!(count_inversions(&cards("5H 2S 9C AS KD"), true), 2);
+        assert_eq!(count_inversions(&cards("2S AS"), false), 1);
+    }
+
+    #[test]
+    fn pair_probability_tracks_composition() {
+        let mut deck = Deck::from_cards(STANDARD_ORDER.to_vec());
+        assert!((pair_probability(&deck) - 3.0 / 51.0).abs() < 1e-12);
+
+        let mut aces = Deck::from_compact_string("AH AS").unwrap();
+        assert_eq!(pair_probability(&aces), 1.0);
+        aces.deal();
+        assert_eq!(pair_probability(&aces), 0.0);
+
+        while deck.deal_matching(Some(Rank::Ace), None).is_some() {}
+        assert!((pair_probability(&deck) - 3.0 / 47.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn deal_fairness_scores_skewed_hands_higher() {
+        let cards = |codes: &str| Deck::from_compact_string(codes).unwrap().into_vec();
+        let even = [cards("AS 2H"), cards("AH 2S"), cards("KD 3C")];
+        let skewed = [cards("AS AH"), cards("2S 2H")];
+
+        assert_eq!(deal_fairness(&even, true), 0.0);
+        assert_eq!(deal_fairness(&skewed, true), 144.0);
+        assert_eq!(deal_fairness(&[], true), 0.0);
+    }
+
+    #[test]
+    fn full_deal_must_use_every_card() {
+        let cards = STANDARD_ORDER.to_vec();
+        let even = [cards[..26].to_vec(), cards[26..].to_vec()];
+        let short = [cards[..25].to_vec(), cards[26..].to_vec()];
+
+        assert!(is_full_deal(&even, 52));
+        assert!(!is_full_deal(&short, 52));
+    }
+}
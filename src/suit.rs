@@ -11,19 +11,103 @@ pub enum Suit {
     Clubs,
 }
 
+/// The two colors of Suits in a French deck of cards.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Black,
+}
+
 impl Suit {
     /// All Suit values for easy iteration
     pub const VALUES: [Suit; 4] = [Self::Hearts, Self::Clubs, Self::Diamonds, Self::Spades];
+
+    /// Get the [Color] of the Suit.
+    pub fn color(&self) -> Color {
+        match self {
+            Suit::Hearts | Suit::Diamonds => Color::Red,
+            Suit::Spades | Suit::Clubs => Color::Black,
+        }
+    }
+
+    /// Get the single letter code of the Suit.
+    pub fn short_code(&self) -> char {
+        match self {
+            Suit::Hearts => 'H',
+            Suit::Spades => 'S',
+            Suit::Diamonds => 'D',
+            Suit::Clubs => 'C',
+        }
+    }
+
+    /// Get the Suit matching a single letter code, ignoring case.
+    pub fn from_short_code(code: char) -> Option<Suit> {
+        Suit::VALUES
+            .into_iter()
+            .find(|suit| suit.short_code() == code.to_ascii_uppercase())
+    }
+
+    /// Get the display name of every Suit, in the order of [Suit::VALUES].
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        Suit::VALUES.into_iter().map(|suit| suit.name())
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Suit::Hearts => "Hearts",
+            Suit::Spades => "Spades",
+            Suit::Diamonds => "Diamonds",
+            Suit::Clubs => "Clubs",
+        }
+    }
+
+    /// Get the Unicode symbol of the Suit.
+    pub fn symbol(&self) -> char {
+        match self {
+            Suit::Hearts => '♥',
+            Suit::Spades => '♠',
+            Suit::Diamonds => '♦',
+            Suit::Clubs => '♣',
+        }
+    }
+}
+
+/// Conventions for ordering Suits from lowest to highest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SuitOrder {
+    /// Clubs, Diamonds, Hearts, Spades, as used for bidding in Bridge.
+    Bridge,
+    /// Suits ordered by name, from Clubs to Spades.
+    Alphabetical,
+    /// Diamonds, Clubs, Hearts, Spades, so neighbouring Suits differ in [Color].
+    Alternating,
+}
+
+/// Get the position of `suit` under `order`, from 1 for the lowest to 4 for the highest.
+pub fn suit_rank(suit: Suit, order: SuitOrder) -> u8 {
+    match (order, suit) {
+        (SuitOrder::Bridge | SuitOrder::Alphabetical, Suit::Clubs) => 1,
+        (SuitOrder::Bridge | SuitOrder::Alphabetical, Suit::Diamonds) => 2,
+        (SuitOrder::Alternating, Suit::Diamonds) => 1,
+        (SuitOrder::Alternating, Suit::Clubs) => 2,
+        (_, Suit::Hearts) => 3,
+        (_, Suit::Spades) => 4,
+    }
 }
 
 /// Returns a user-friendly string representation of the Suit
 impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returns a user-friendly string representation of the Color
+impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Suit::Hearts => write!(f, "Hearts"),
-            Suit::Spades => write!(f, "Spades"),
-            Suit::Diamonds => write!(f, "Diamonds"),
-            Suit::Clubs => write!(f, "Clubs"),
+            Color::Red => write!(f, "Red"),
+            Color::Black => write!(f, "Black"),
         }
     }
 }
@@ -44,4 +128,23 @@ mod tests {
 
         assert_eq!(expected_names, actual_names);
     }
+
+    #[test]
+    fn suit_rank_follows_chosen_order() {
+        assert_eq!(suit_rank(Suit::Spades, SuitOrder::Bridge), 4);
+        assert_eq!(suit_rank(Suit::Clubs, SuitOrder::Alphabetical), 1);
+        assert_eq!(suit_rank(Suit::Diamonds, SuitOrder::Alternating), 1);
+
+        let mut suits = Suit::VALUES;
+        suits.sort_by_key(|suit| suit_rank(*suit, SuitOrder::Alternating));
+        let colors = suits.map(|suit| suit.color());
+        assert!(colors.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn names_follow_canonical_order() {
+        let names: Vec<&str> = Suit::names().collect();
+
+        assert_eq!(names, ["Hearts", "Clubs", "Diamonds", "Spades"]);
+    }
 }
@@ -0,0 +1,130 @@
+//! Dealing helpers for a game of Texas Hold'em.
+//!
+//! The community board is dealt in three rounds: the flop (3 [Card]s), the
+//! turn (1 [Card]) and the river (1 [Card]). A [Card] is burned from the top of
+//! the [Deck] before each round, as in a standard game.
+
+use crate::card::Card;
+use crate::deck::{DealDirection, Deck, Finished};
+
+/// Number of [Card]s a full board removes from the [Deck], burns included.
+const BOARD_SIZE: usize = 8;
+
+/// The five community [Card]s shared by every player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommunityCards {
+    flop: [Card; 3],
+    turn: Card,
+    river: Card,
+}
+
+impl CommunityCards {
+    /// Get the three [Card]s of the flop.
+    pub fn flop(&self) -> [Card; 3] {
+        self.flop
+    }
+
+    /// Get the [Card] dealt on the turn.
+    pub fn turn(&self) -> Card {
+        self.turn
+    }
+
+    /// Get the [Card] dealt on the river.
+    pub fn river(&self) -> Card {
+        self.river
+    }
+
+    /// Get all five community [Card]s in the order they were dealt.
+    pub fn all(&self) -> [Card; 5] {
+        let [first, second, third] = self.flop;
+        [first, second, third, self.turn, self.river]
+    }
+}
+
+/// A dealer for a hand of Texas Hold'em, keeping track of the burned [Card]s.
+#[derive(Debug, Default)]
+pub struct TexasHoldem {
+    burned: Vec<Card>,
+}
+
+impl TexasHoldem {
+    /// Creates a new dealer with no burned [Card]s.
+    pub fn new() -> TexasHoldem {
+        TexasHoldem { burned: Vec::new() }
+    }
+
+    /// Burns and deals the flop, turn and river from the [Deck].
+    ///
+    /// Returns `None` without touching the [Deck] if it holds fewer than the 8
+    /// [Card]s needed for a full board.
+    pub fn deal_community(&mut self, deck: &mut Deck<Finished>) -> Option<CommunityCards> {
+        if deck.total_cards() < BOARD_SIZE {
+            return None;
+        }
+
+        self.burn(deck)?;
+        let flop = [
+            deck.deal_top_card()?,
+            deck.deal_top_card()?,
+            deck.deal_top_card()?,
+        ];
+        self.burn(deck)?;
+        let turn = deck.deal_top_card()?;
+        self.burn(deck)?;
+        let river = deck.deal_top_card()?;
+
+        Some(CommunityCards { flop, turn, river })
+    }
+
+    /// Get the [Card]s burned so far, in the order they were burned.
+    pub fn burned(&self) -> &[Card] {
+        &self.burned
+    }
+
+    fn burn(&mut self, deck: &mut Deck<Finished>) -> Option<()> {
+        self.burned.push(deck.deal_top_card()?);
+        Some(())
+    }
+}
+
+impl Deck<Finished> {
+    /// Deals `hole_cards` [Card]s to each of `players`, one at a time around the table.
+    ///
+    /// The small blind, seat 0, receives the first [Card] and the dealer the last
+    /// of each round, as in a real deal. Dealing stops early if the [Deck] runs out.
+    pub fn post_blinds_and_deal(&mut self, players: usize, hole_cards: usize) -> Vec<Vec<Card>> {
+        self.deal_around(players, DealDirection::Clockwise, 0, hole_cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deal_community_removes_five_cards_and_three_burns() {
+        let mut deck = Deck::default_new();
+        let mut dealer = TexasHoldem::new();
+
+        let board = dealer.deal_community(&mut deck);
+
+        assert!(board.is_some());
+        assert_eq!(deck.total_cards(), 52 - 8);
+        assert_eq!(dealer.burned().len(), 3);
+    }
+
+    #[test]
+    fn hole_cards_are_dealt_one_at_a_time() {
+        let order = Deck::default_new();
+        let top: Vec<Card> = order.iter().copied().collect();
+        let mut deck = order.clone();
+
+        let hands = deck.post_blinds_and_deal(9, 2);
+
+        assert_eq!(deck.total_cards(), 52 - 18);
+        assert_eq!(hands.len(), 9);
+        for (seat, hand) in hands.iter().enumerate() {
+            assert_eq!(hand, &vec![top[seat], top[seat + 9]]);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Trick resolution for trick-taking games such as Euchre and Bridge.
+//!
+//! Each player plays one [Card] to a trick. Trumps beat every other [Suit];
+//! otherwise only [Card]s following the lead [Suit] can win. Within a [Suit],
+//! the highest [Rank](crate::rank::Rank) wins.
+
+use crate::card::Card;
+use crate::deck::{Deck, Finished};
+use crate::suit::Suit;
+
+/// Get the [Card] that wins a trick, given the lead [Suit] and an optional trump [Suit].
+///
+/// `cards` are in the order they were played. Returns `None` for an empty trick.
+pub fn winning_card(
+    cards: &[Card],
+    lead: Suit,
+    trump: Option<Suit>,
+    aces_high: bool,
+) -> Option<Card> {
+    winning_index(cards, lead, trump, aces_high).map(|winner| cards[winner])
+}
+
+/// Resolves a trick, returning the position in `cards` of the winning play and its [Card].
+///
+/// `cards` are in the order they were played, so the position is relative to
+/// the player who led.
+///
+/// # Panics
+/// Panics if `cards` is empty, as a trick needs at least one play.
+pub fn resolve_trick(
+    cards: Vec<Card>,
+    lead: Suit,
+    trump: Option<Suit>,
+    aces_high: bool,
+) -> (usize, Card) {
+    let winner = winning_index(&cards, lead, trump, aces_high).expect("trick has no cards");
+    (winner, cards[winner])
+}
+
+/// Get the position of the [Card] that wins a trick, or `None` for an empty trick.
+fn winning_index(
+    cards: &[Card],
+    lead: Suit,
+    trump: Option<Suit>,
+    aces_high: bool,
+) -> Option<usize> {
+    let first = cards.first()?;
+
+    let (winner, _) = cards
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold((0, first), |best, play| {
+            match beats(play.1, best.1, lead, trump, aces_high) {
+                true => play,
+                false => best,
+            }
+        });
+
+    Some(winner)
+}
+
+impl Deck<Finished> {
+    /// Counts the [Card]s left that would take a trick currently won by `card`.
+    ///
+    /// Uses the same rules as [winning_card], so a count of 0 means `card` cannot
+    /// be beaten by anything still in the [Deck].
+    pub fn cards_beating(
+        &self,
+        card: &Card,
+        lead: Suit,
+        trump: Option<Suit>,
+        aces_high: bool,
+    ) -> usize {
+        self.iter()
+            .filter(|challenger| beats(challenger, card, lead, trump, aces_high))
+            .count()
+    }
+}
+
+/// Returns whether `challenger` takes the trick from the `current` winner.
+fn beats(
+    challenger: &Card,
+    current: &Card,
+    lead: Suit,
+    trump: Option<Suit>,
+    aces_high: bool,
+) -> bool {
+    if challenger.is_same_suit(current) {
+        return challenger.rank().get_numerical_rank(aces_high)
+            > current.rank().get_numerical_rank(aces_high);
+    }
+
+    let is_trump = |card: &Card| Some(card.suit()) == trump;
+    match (is_trump(challenger), is_trump(current)) {
+        (true, _) => true,
+        (false, true) => false,
+        (false, false) => challenger.suit() == lead,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rank::Rank;
+
+    fn cards(codes: &[&str]) -> Vec<Card> {
+        codes.iter().map(|code| code.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn low_trump_beats_high_lead_card() {
+        let trick = cards(&["AH", "KH", "2S", "QH"]);
+
+        let winner = winning_card(&trick, Suit::Hearts, Some(Suit::Spades), true);
+
+        assert_eq!(winner, Some(Card::new(Rank::Two, Suit::Spades)));
+    }
+
+    #[test]
+    fn off_suit_card_cannot_win_without_trump() {
+        let trick = cards(&["9D", "AC", "JD"]);
+
+        let winner = winning_card(&trick, Suit::Diamonds, None, true);
+
+        assert_eq!(winner, Some(cards(&["JD"])[0]));
+    }
+
+    #[test]
+    fn resolve_trick_finds_the_trumping_player() {
+        let trick = cards(&["KH", "AH", "3D", "QH"]);
+
+        let (winner, card) = resolve_trick(trick, Suit::Hearts, Some(Suit::Diamonds), true);
+
+        assert_eq!(winner, 2);
+        assert_eq!(card, Card::new(Rank::Three, Suit::Diamonds));
+    }
+
+    #[test]
+    fn cards_beating_counts_higher_cards_of_the_lead_suit() {
+        let three = Card::new(Rank::Three, Suit::Hearts);
+        let mut deck = Deck::from_cards(crate::deck::STANDARD_ORDER.to_vec());
+        deck.remove_card(&three);
+
+        assert_eq!(deck.cards_beating(&three, Suit::Hearts, None, true), 11);
+        assert_eq!(
+            deck.cards_beating(&three, Suit::Hearts, Some(Suit::Clubs), true),
+            24
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! A compact binary encoding of a [Deck] for syncing it over a network.
+//!
+//! Each [Card] takes one byte, its position in [STANDARD_ORDER], listed from
+//! the top of the [Deck]. Two trailing bytes hold the [Deck]'s `checksum`, big
+//! endian, so a corrupted or truncated message is rejected rather than dealt.
+
+use crate::card::Card;
+use crate::deck::{Deck, Finished, Start, STANDARD_ORDER};
+use std::error::Error;
+use std::fmt;
+
+/// Number of bytes the checksum adds to the end of an encoded [Deck].
+const CHECKSUM_SIZE: usize = 2;
+
+/// Errors from decoding a [Deck] with `Deck::from_wire`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireError {
+    /// The bytes are too short to hold a checksum.
+    Truncated,
+    /// A byte is not the position of a [Card] in [STANDARD_ORDER].
+    InvalidCard(u8),
+    /// The checksum carried by the bytes does not match the [Card]s decoded.
+    ChecksumMismatch { expected: u16, found: u16 },
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::Truncated => write!(f, "the encoded deck is missing its checksum"),
+            WireError::InvalidCard(byte) => write!(f, "byte {} is not a valid card", byte),
+            WireError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum {:#06x} does not match the cards' checksum {:#06x}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl Error for WireError {}
+
+impl Deck<Start> {
+    /// Decode a [Deck] encoded with `to_wire`, the first [Card] being the top.
+    ///
+    /// Returns [WireError::Truncated] if the bytes are too short to hold a
+    /// checksum, [WireError::InvalidCard] for a byte that is not a [Card], and
+    /// [WireError::ChecksumMismatch] if the [Card]s were altered on the way.
+    pub fn from_wire(bytes: &[u8]) -> Result<Deck<Finished>, WireError> {
+        let split = bytes
+            .len()
+            .checked_sub(CHECKSUM_SIZE)
+            .ok_or(WireError::Truncated)?;
+        let (body, trailer) = bytes.split_at(split);
+
+        let expected = u16::from_be_bytes([trailer[0], trailer[1]]);
+        let found = fletcher16(body);
+        if expected != found {
+            return Err(WireError::ChecksumMismatch { expected, found });
+        }
+
+        let cards = body
+            .iter()
+            .map(|byte| {
+                STANDARD_ORDER
+                    .get(usize::from(*byte))
+                    .copied()
+                    .ok_or(WireError::InvalidCard(*byte))
+            })
+            .collect::<Result<Vec<Card>, WireError>>()?;
+
+        Ok(Deck::from_cards(cards))
+    }
+}
+
+impl Deck<Finished> {
+    /// Encode the [Card]s left, top first, followed by their `checksum`.
+    pub fn to_wire(&self) -> Vec<u8> {
+        let mut bytes = self.card_bytes();
+        let checksum = fletcher16(&bytes);
+        bytes.extend(checksum.to_be_bytes());
+        bytes
+    }
+
+    /// Get a Fletcher-16 checksum of the [Card]s left, which changes if any [Card]
+    /// is replaced or moved.
+    pub fn checksum(&self) -> u16 {
+        fletcher16(&self.card_bytes())
+    }
+
+    fn card_bytes(&self) -> Vec<u8> {
+        self.iter()
+            .map(|card| {
+                // Every Card is in STANDARD_ORDER, whose 52 positions fit in a byte.
+                STANDARD_ORDER
+                    .iter()
+                    .position(|standard| standard == card)
+                    .unwrap_or_default() as u8
+            })
+            .collect()
+    }
+}
+
+/// Computes the Fletcher-16 checksum of `bytes`, which depends on their order.
+fn fletcher16(bytes: &[u8]) -> u16 {
+    let (sum, weighted) = bytes.iter().fold((0u16, 0u16), |(sum, weighted), byte| {
+        let sum = (sum + u16::from(*byte)) % 255;
+        (sum, (weighted + sum) % 255)
+    });
+
+    (weighted << 8) | sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wire_round_trips_a_shuffled_deck() {
+        let mut deck = Deck::default_new_seeded(9);
+        deck.deal_top_card();
+
+        let bytes = deck.to_wire();
+        let decoded = Deck::from_wire(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), 51 + CHECKSUM_SIZE);
+        assert_eq!(decoded.checksum(), deck.checksum());
+        assert_eq!(decoded.into_vec(), deck.into_vec());
+    }
+
+    #[test]
+    fn corrupted_byte_fails_the_checksum() {
+        let mut bytes = Deck::default_new_seeded(9).to_wire();
+        bytes[1] ^= 0x01;
+
+        assert!(matches!(
+            Deck::from_wire(&bytes),
+            Err(WireError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(Deck::from_wire(&[7]), Err(WireError::Truncated)));
+    }
+}